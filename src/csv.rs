@@ -0,0 +1,174 @@
+use crate::tuple::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub quote: char,
+    pub header: bool, // the first row gives the names of the fields of the other rows
+}
+
+impl CsvOptions {
+    pub fn new() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            header: false,
+        }
+    }
+}
+
+impl Clone for CsvOptions {
+    fn clone(&self) -> Self {
+        Self {
+            delimiter: self.delimiter,
+            quote: self.quote,
+            header: self.header,
+        }
+    }
+}
+
+// rows of a file read one at a time by csv_rows()
+pub struct CsvReader {
+    pub reader: BufReader<File>,
+    pub options: CsvOptions,
+    pub names: Option<Vec<String>>, // the header once it is read
+    pub peeked: Option<Tuple>,      // row already read by has_next but not yet taken by next
+}
+
+impl CsvReader {
+    pub fn new(file: File, options: CsvOptions) -> Self {
+        Self {
+            reader: BufReader::new(file),
+            options: options,
+            names: None,
+            peeked: None,
+        }
+    }
+
+    pub fn next_row(&mut self) -> Result<Option<Tuple>, String> {
+        if let Some(row) = self.peeked.take() {
+            return Ok(Some(row));
+        }
+
+        if self.options.header && self.names.is_none() {
+            match read_record(&mut self.reader, &self.options)? {
+                Some(names) => self.names = Some(names),
+                None => return Ok(None),
+            }
+        }
+
+        match read_record(&mut self.reader, &self.options)? {
+            Some(fields) => Ok(Some(get_row(fields, &self.names))),
+            None => Ok(None),
+        }
+    }
+}
+
+// the fields of the next record, a quoted field can go over multiple lines, None at the end
+pub fn read_record<R: BufRead>(
+    reader: &mut R,
+    options: &CsvOptions,
+) -> Result<Option<Vec<String>>, String> {
+    let mut line = String::new();
+
+    // empty lines aren't records
+    loop {
+        line.clear();
+
+        match reader.read_line(&mut line) {
+            Ok(0) => return Ok(None),
+            Ok(_) => {}
+            Err(err) => return Err(err.to_string()),
+        }
+
+        if !line.trim_end_matches(&['\r', '\n'][..]).is_empty() {
+            break;
+        }
+    }
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+
+    loop {
+        let mut chars = line.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if quoted {
+                if ch != options.quote {
+                    field.push(ch);
+                } else if chars.peek() == Some(&options.quote) {
+                    // "" is a quote inside of a quoted field
+                    field.push(ch);
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else if ch == options.quote {
+                quoted = true;
+            } else if ch == options.delimiter {
+                fields.push(std::mem::take(&mut field));
+            } else if ch != '\r' && ch != '\n' {
+                field.push(ch);
+            }
+        }
+
+        if !quoted {
+            break;
+        }
+
+        line.clear();
+
+        match reader.read_line(&mut line) {
+            Ok(0) => return Err(String::from("a quoted field is never closed")),
+            Ok(_) => {}
+            Err(err) => return Err(err.to_string()),
+        }
+    }
+
+    fields.push(field);
+
+    return Ok(Some(fields));
+}
+
+// a named tuple with a header, the fields past the end of the header don't have a name
+pub fn get_row(fields: Vec<String>, names: &Option<Vec<String>>) -> Tuple {
+    let mut row = Tuple::new();
+
+    for (i, field) in fields.into_iter().enumerate() {
+        match names.as_ref().and_then(|names| names.get(i)) {
+            Some(name) => row.set_string(name, field),
+            None => row.set_string("", field),
+        }
+    }
+
+    // a short row still has every name
+    if let Some(names) = names {
+        for i in row.len()..(names.len()) {
+            row.set_null(&names[i]);
+        }
+    }
+
+    return row;
+}
+
+// quoted only if it has to be, a quote inside is doubled
+pub fn get_field(value: &str, options: &CsvOptions) -> String {
+    let needs_quotes = value.contains(options.delimiter)
+        || value.contains(options.quote)
+        || value.contains('\n')
+        || value.contains('\r');
+
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    let quote = options.quote.to_string();
+    let escaped = value.replace(&quote, &format!("{}{}", quote, quote));
+
+    return format!("{}{}{}", quote, escaped, quote);
+}
@@ -1,15 +1,43 @@
 use crate::bigint_pow;
+use crate::csv::*;
+use crate::function::*;
+use crate::get_real_name;
+use crate::handle::*;
+use crate::http;
+use crate::instruction_fn::compare;
+use crate::iterator::*;
+use crate::json::parse_json;
 use crate::kind::*;
+use crate::process::*;
+use crate::range::*;
 use crate::table::*;
 use crate::tuple::*;
+use crate::variable::*;
 use crate::vec_table::*;
-use num::{BigInt, FromPrimitive, Zero};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use json::JsonValue;
+use num::bigint::Sign;
+use num::{BigInt, FromPrimitive, Integer, One, Signed, ToPrimitive, Zero};
 use rand::prelude::*;
+use regex::Regex;
+use sha2::Digest;
+use std::cmp::Ordering;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use sysinfo::{DiskExt, ProcessExt, ProcessorExt, System, SystemExt};
+#[cfg(target_family = "unix")]
+use termion::color::{AnsiValue, Bg, Color, Fg, Rgb};
+#[cfg(target_family = "unix")]
+use termion::raw::IntoRawMode;
+use websocket::sync::server::IntoWs;
+use websocket::sync::Client;
+use websocket::{ClientBuilder, OwnedMessage, WebSocketError};
 
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
 
-pub const DEFAULTS_FUNCTIONS: [DefaultFunction; 12] = [
+pub const DEFAULTS_FUNCTIONS: [DefaultFunction; 171] = [
     DefaultFunction::Read,
     DefaultFunction::Pause,
     DefaultFunction::Print,
@@ -22,6 +50,165 @@ pub const DEFAULTS_FUNCTIONS: [DefaultFunction; 12] = [
     DefaultFunction::Rand,
     DefaultFunction::Kind,
     DefaultFunction::Parse,
+    DefaultFunction::Iter,
+    DefaultFunction::Next,
+    DefaultFunction::HasNext,
+    DefaultFunction::Len,
+    DefaultFunction::Step,
+    DefaultFunction::Slice,
+    DefaultFunction::At,
+    DefaultFunction::Assert,
+    DefaultFunction::Split,
+    DefaultFunction::Join,
+    DefaultFunction::Replace,
+    DefaultFunction::Find,
+    DefaultFunction::StartsWith,
+    DefaultFunction::EndsWith,
+    DefaultFunction::Upper,
+    DefaultFunction::Lower,
+    DefaultFunction::Trim,
+    DefaultFunction::Substr,
+    DefaultFunction::Chars,
+    DefaultFunction::Repeat,
+    DefaultFunction::Contains,
+    DefaultFunction::Abs,
+    DefaultFunction::Min,
+    DefaultFunction::Max,
+    DefaultFunction::Sin,
+    DefaultFunction::Cos,
+    DefaultFunction::Tan,
+    DefaultFunction::Asin,
+    DefaultFunction::Acos,
+    DefaultFunction::Atan,
+    DefaultFunction::Exp,
+    DefaultFunction::Ln,
+    DefaultFunction::Log10,
+    DefaultFunction::Log,
+    DefaultFunction::Hypot,
+    DefaultFunction::Pi,
+    DefaultFunction::E,
+    DefaultFunction::Clamp,
+    DefaultFunction::Gcd,
+    DefaultFunction::Lcm,
+    DefaultFunction::Factorial,
+    DefaultFunction::Modpow,
+    DefaultFunction::Isqrt,
+    DefaultFunction::IsPrime,
+    DefaultFunction::Seed,
+    DefaultFunction::Randint,
+    DefaultFunction::Choice,
+    DefaultFunction::Shuffle,
+    DefaultFunction::Sample,
+    DefaultFunction::ReadFile,
+    DefaultFunction::ReadBytes,
+    DefaultFunction::WriteFile,
+    DefaultFunction::AppendFile,
+    DefaultFunction::ReadLines,
+    DefaultFunction::Exists,
+    DefaultFunction::Remove,
+    DefaultFunction::Mkdir,
+    DefaultFunction::ListDir,
+    DefaultFunction::Metadata,
+    DefaultFunction::Open,
+    DefaultFunction::Readline,
+    DefaultFunction::Write,
+    DefaultFunction::Close,
+    DefaultFunction::JsonParse,
+    DefaultFunction::JsonStringify,
+    DefaultFunction::WsConnect,
+    DefaultFunction::WsSend,
+    DefaultFunction::WsRecv,
+    DefaultFunction::WsClose,
+    DefaultFunction::WsListen,
+    DefaultFunction::CpuCount,
+    DefaultFunction::CpuUsage,
+    DefaultFunction::Memory,
+    DefaultFunction::Swap,
+    DefaultFunction::LoadAverage,
+    DefaultFunction::Disks,
+    DefaultFunction::Processes,
+    DefaultFunction::Pid,
+    DefaultFunction::Now,
+    DefaultFunction::Monotonic,
+    DefaultFunction::Sleep,
+    DefaultFunction::Date,
+    DefaultFunction::Strftime,
+    DefaultFunction::Timeit,
+    DefaultFunction::Env,
+    DefaultFunction::SetEnv,
+    DefaultFunction::EnvAll,
+    DefaultFunction::Exec,
+    DefaultFunction::ExecStream,
+    DefaultFunction::Exit,
+    DefaultFunction::ReMatch,
+    DefaultFunction::ReFindAll,
+    DefaultFunction::ReReplace,
+    DefaultFunction::ReSplit,
+    DefaultFunction::Eprint,
+    DefaultFunction::Format,
+    DefaultFunction::Input,
+    DefaultFunction::ReadAll,
+    DefaultFunction::ReadInt,
+    DefaultFunction::ReadFloat,
+    DefaultFunction::Str,
+    DefaultFunction::Float,
+    DefaultFunction::Bool,
+    DefaultFunction::Bigint,
+    DefaultFunction::ToRadix,
+    DefaultFunction::ToTuple,
+    DefaultFunction::Repr,
+    DefaultFunction::Push,
+    DefaultFunction::Pop,
+    DefaultFunction::Concat,
+    DefaultFunction::Reverse,
+    DefaultFunction::Sort,
+    DefaultFunction::Sorted,
+    DefaultFunction::Unique,
+    DefaultFunction::IndexOf,
+    DefaultFunction::Sum,
+    DefaultFunction::Zip,
+    DefaultFunction::Enumerate,
+    DefaultFunction::Map,
+    DefaultFunction::Filter,
+    DefaultFunction::Reduce,
+    DefaultFunction::TermSize,
+    DefaultFunction::Clear,
+    DefaultFunction::MoveCursor,
+    DefaultFunction::Color,
+    DefaultFunction::Bold,
+    DefaultFunction::Underline,
+    DefaultFunction::ShowCursor,
+    DefaultFunction::HideCursor,
+    DefaultFunction::Getkey,
+    DefaultFunction::Base64Encode,
+    DefaultFunction::Base64Decode,
+    DefaultFunction::HexEncode,
+    DefaultFunction::HexDecode,
+    DefaultFunction::UrlEncode,
+    DefaultFunction::UrlDecode,
+    DefaultFunction::Utf8Bytes,
+    DefaultFunction::FromUtf8,
+    DefaultFunction::Crc32,
+    DefaultFunction::Md5,
+    DefaultFunction::Sha1,
+    DefaultFunction::Sha256,
+    DefaultFunction::Hash,
+    DefaultFunction::CsvParse,
+    DefaultFunction::CsvStringify,
+    DefaultFunction::CsvRows,
+    DefaultFunction::TcpConnect,
+    DefaultFunction::TcpListen,
+    DefaultFunction::Accept,
+    DefaultFunction::Send,
+    DefaultFunction::Recv,
+    DefaultFunction::UdpBind,
+    DefaultFunction::UdpSendTo,
+    DefaultFunction::UdpRecvFrom,
+    DefaultFunction::LocalAddr,
+    DefaultFunction::HttpGet,
+    DefaultFunction::HttpRequest,
+    DefaultFunction::HttpServe,
+    DefaultFunction::StdinLines,
 ];
 
 pub const DEFAULTS_FUNCTIONS_STR: [&str; DEFAULTS_FUNCTIONS.len()] = [
@@ -37,6 +224,165 @@ pub const DEFAULTS_FUNCTIONS_STR: [&str; DEFAULTS_FUNCTIONS.len()] = [
     DEFAULTS_FUNCTIONS[9].get_str(),
     DEFAULTS_FUNCTIONS[10].get_str(),
     DEFAULTS_FUNCTIONS[11].get_str(),
+    DEFAULTS_FUNCTIONS[12].get_str(),
+    DEFAULTS_FUNCTIONS[13].get_str(),
+    DEFAULTS_FUNCTIONS[14].get_str(),
+    DEFAULTS_FUNCTIONS[15].get_str(),
+    DEFAULTS_FUNCTIONS[16].get_str(),
+    DEFAULTS_FUNCTIONS[17].get_str(),
+    DEFAULTS_FUNCTIONS[18].get_str(),
+    DEFAULTS_FUNCTIONS[19].get_str(),
+    DEFAULTS_FUNCTIONS[20].get_str(),
+    DEFAULTS_FUNCTIONS[21].get_str(),
+    DEFAULTS_FUNCTIONS[22].get_str(),
+    DEFAULTS_FUNCTIONS[23].get_str(),
+    DEFAULTS_FUNCTIONS[24].get_str(),
+    DEFAULTS_FUNCTIONS[25].get_str(),
+    DEFAULTS_FUNCTIONS[26].get_str(),
+    DEFAULTS_FUNCTIONS[27].get_str(),
+    DEFAULTS_FUNCTIONS[28].get_str(),
+    DEFAULTS_FUNCTIONS[29].get_str(),
+    DEFAULTS_FUNCTIONS[30].get_str(),
+    DEFAULTS_FUNCTIONS[31].get_str(),
+    DEFAULTS_FUNCTIONS[32].get_str(),
+    DEFAULTS_FUNCTIONS[33].get_str(),
+    DEFAULTS_FUNCTIONS[34].get_str(),
+    DEFAULTS_FUNCTIONS[35].get_str(),
+    DEFAULTS_FUNCTIONS[36].get_str(),
+    DEFAULTS_FUNCTIONS[37].get_str(),
+    DEFAULTS_FUNCTIONS[38].get_str(),
+    DEFAULTS_FUNCTIONS[39].get_str(),
+    DEFAULTS_FUNCTIONS[40].get_str(),
+    DEFAULTS_FUNCTIONS[41].get_str(),
+    DEFAULTS_FUNCTIONS[42].get_str(),
+    DEFAULTS_FUNCTIONS[43].get_str(),
+    DEFAULTS_FUNCTIONS[44].get_str(),
+    DEFAULTS_FUNCTIONS[45].get_str(),
+    DEFAULTS_FUNCTIONS[46].get_str(),
+    DEFAULTS_FUNCTIONS[47].get_str(),
+    DEFAULTS_FUNCTIONS[48].get_str(),
+    DEFAULTS_FUNCTIONS[49].get_str(),
+    DEFAULTS_FUNCTIONS[50].get_str(),
+    DEFAULTS_FUNCTIONS[51].get_str(),
+    DEFAULTS_FUNCTIONS[52].get_str(),
+    DEFAULTS_FUNCTIONS[53].get_str(),
+    DEFAULTS_FUNCTIONS[54].get_str(),
+    DEFAULTS_FUNCTIONS[55].get_str(),
+    DEFAULTS_FUNCTIONS[56].get_str(),
+    DEFAULTS_FUNCTIONS[57].get_str(),
+    DEFAULTS_FUNCTIONS[58].get_str(),
+    DEFAULTS_FUNCTIONS[59].get_str(),
+    DEFAULTS_FUNCTIONS[60].get_str(),
+    DEFAULTS_FUNCTIONS[61].get_str(),
+    DEFAULTS_FUNCTIONS[62].get_str(),
+    DEFAULTS_FUNCTIONS[63].get_str(),
+    DEFAULTS_FUNCTIONS[64].get_str(),
+    DEFAULTS_FUNCTIONS[65].get_str(),
+    DEFAULTS_FUNCTIONS[66].get_str(),
+    DEFAULTS_FUNCTIONS[67].get_str(),
+    DEFAULTS_FUNCTIONS[68].get_str(),
+    DEFAULTS_FUNCTIONS[69].get_str(),
+    DEFAULTS_FUNCTIONS[70].get_str(),
+    DEFAULTS_FUNCTIONS[71].get_str(),
+    DEFAULTS_FUNCTIONS[72].get_str(),
+    DEFAULTS_FUNCTIONS[73].get_str(),
+    DEFAULTS_FUNCTIONS[74].get_str(),
+    DEFAULTS_FUNCTIONS[75].get_str(),
+    DEFAULTS_FUNCTIONS[76].get_str(),
+    DEFAULTS_FUNCTIONS[77].get_str(),
+    DEFAULTS_FUNCTIONS[78].get_str(),
+    DEFAULTS_FUNCTIONS[79].get_str(),
+    DEFAULTS_FUNCTIONS[80].get_str(),
+    DEFAULTS_FUNCTIONS[81].get_str(),
+    DEFAULTS_FUNCTIONS[82].get_str(),
+    DEFAULTS_FUNCTIONS[83].get_str(),
+    DEFAULTS_FUNCTIONS[84].get_str(),
+    DEFAULTS_FUNCTIONS[85].get_str(),
+    DEFAULTS_FUNCTIONS[86].get_str(),
+    DEFAULTS_FUNCTIONS[87].get_str(),
+    DEFAULTS_FUNCTIONS[88].get_str(),
+    DEFAULTS_FUNCTIONS[89].get_str(),
+    DEFAULTS_FUNCTIONS[90].get_str(),
+    DEFAULTS_FUNCTIONS[91].get_str(),
+    DEFAULTS_FUNCTIONS[92].get_str(),
+    DEFAULTS_FUNCTIONS[93].get_str(),
+    DEFAULTS_FUNCTIONS[94].get_str(),
+    DEFAULTS_FUNCTIONS[95].get_str(),
+    DEFAULTS_FUNCTIONS[96].get_str(),
+    DEFAULTS_FUNCTIONS[97].get_str(),
+    DEFAULTS_FUNCTIONS[98].get_str(),
+    DEFAULTS_FUNCTIONS[99].get_str(),
+    DEFAULTS_FUNCTIONS[100].get_str(),
+    DEFAULTS_FUNCTIONS[101].get_str(),
+    DEFAULTS_FUNCTIONS[102].get_str(),
+    DEFAULTS_FUNCTIONS[103].get_str(),
+    DEFAULTS_FUNCTIONS[104].get_str(),
+    DEFAULTS_FUNCTIONS[105].get_str(),
+    DEFAULTS_FUNCTIONS[106].get_str(),
+    DEFAULTS_FUNCTIONS[107].get_str(),
+    DEFAULTS_FUNCTIONS[108].get_str(),
+    DEFAULTS_FUNCTIONS[109].get_str(),
+    DEFAULTS_FUNCTIONS[110].get_str(),
+    DEFAULTS_FUNCTIONS[111].get_str(),
+    DEFAULTS_FUNCTIONS[112].get_str(),
+    DEFAULTS_FUNCTIONS[113].get_str(),
+    DEFAULTS_FUNCTIONS[114].get_str(),
+    DEFAULTS_FUNCTIONS[115].get_str(),
+    DEFAULTS_FUNCTIONS[116].get_str(),
+    DEFAULTS_FUNCTIONS[117].get_str(),
+    DEFAULTS_FUNCTIONS[118].get_str(),
+    DEFAULTS_FUNCTIONS[119].get_str(),
+    DEFAULTS_FUNCTIONS[120].get_str(),
+    DEFAULTS_FUNCTIONS[121].get_str(),
+    DEFAULTS_FUNCTIONS[122].get_str(),
+    DEFAULTS_FUNCTIONS[123].get_str(),
+    DEFAULTS_FUNCTIONS[124].get_str(),
+    DEFAULTS_FUNCTIONS[125].get_str(),
+    DEFAULTS_FUNCTIONS[126].get_str(),
+    DEFAULTS_FUNCTIONS[127].get_str(),
+    DEFAULTS_FUNCTIONS[128].get_str(),
+    DEFAULTS_FUNCTIONS[129].get_str(),
+    DEFAULTS_FUNCTIONS[130].get_str(),
+    DEFAULTS_FUNCTIONS[131].get_str(),
+    DEFAULTS_FUNCTIONS[132].get_str(),
+    DEFAULTS_FUNCTIONS[133].get_str(),
+    DEFAULTS_FUNCTIONS[134].get_str(),
+    DEFAULTS_FUNCTIONS[135].get_str(),
+    DEFAULTS_FUNCTIONS[136].get_str(),
+    DEFAULTS_FUNCTIONS[137].get_str(),
+    DEFAULTS_FUNCTIONS[138].get_str(),
+    DEFAULTS_FUNCTIONS[139].get_str(),
+    DEFAULTS_FUNCTIONS[140].get_str(),
+    DEFAULTS_FUNCTIONS[141].get_str(),
+    DEFAULTS_FUNCTIONS[142].get_str(),
+    DEFAULTS_FUNCTIONS[143].get_str(),
+    DEFAULTS_FUNCTIONS[144].get_str(),
+    DEFAULTS_FUNCTIONS[145].get_str(),
+    DEFAULTS_FUNCTIONS[146].get_str(),
+    DEFAULTS_FUNCTIONS[147].get_str(),
+    DEFAULTS_FUNCTIONS[148].get_str(),
+    DEFAULTS_FUNCTIONS[149].get_str(),
+    DEFAULTS_FUNCTIONS[150].get_str(),
+    DEFAULTS_FUNCTIONS[151].get_str(),
+    DEFAULTS_FUNCTIONS[152].get_str(),
+    DEFAULTS_FUNCTIONS[153].get_str(),
+    DEFAULTS_FUNCTIONS[154].get_str(),
+    DEFAULTS_FUNCTIONS[155].get_str(),
+    DEFAULTS_FUNCTIONS[156].get_str(),
+    DEFAULTS_FUNCTIONS[157].get_str(),
+    DEFAULTS_FUNCTIONS[158].get_str(),
+    DEFAULTS_FUNCTIONS[159].get_str(),
+    DEFAULTS_FUNCTIONS[160].get_str(),
+    DEFAULTS_FUNCTIONS[161].get_str(),
+    DEFAULTS_FUNCTIONS[162].get_str(),
+    DEFAULTS_FUNCTIONS[163].get_str(),
+    DEFAULTS_FUNCTIONS[164].get_str(),
+    DEFAULTS_FUNCTIONS[165].get_str(),
+    DEFAULTS_FUNCTIONS[166].get_str(),
+    DEFAULTS_FUNCTIONS[167].get_str(),
+    DEFAULTS_FUNCTIONS[168].get_str(),
+    DEFAULTS_FUNCTIONS[169].get_str(),
+    DEFAULTS_FUNCTIONS[170].get_str(),
 ];
 
 pub const DEFAULTS_FUNCTIONS_ARGS: [&[&str]; DEFAULTS_FUNCTIONS.len()] = [
@@ -52,6 +398,165 @@ pub const DEFAULTS_FUNCTIONS_ARGS: [&[&str]; DEFAULTS_FUNCTIONS.len()] = [
     DEFAULTS_FUNCTIONS[9].get_arguments(),
     DEFAULTS_FUNCTIONS[10].get_arguments(),
     DEFAULTS_FUNCTIONS[11].get_arguments(),
+    DEFAULTS_FUNCTIONS[12].get_arguments(),
+    DEFAULTS_FUNCTIONS[13].get_arguments(),
+    DEFAULTS_FUNCTIONS[14].get_arguments(),
+    DEFAULTS_FUNCTIONS[15].get_arguments(),
+    DEFAULTS_FUNCTIONS[16].get_arguments(),
+    DEFAULTS_FUNCTIONS[17].get_arguments(),
+    DEFAULTS_FUNCTIONS[18].get_arguments(),
+    DEFAULTS_FUNCTIONS[19].get_arguments(),
+    DEFAULTS_FUNCTIONS[20].get_arguments(),
+    DEFAULTS_FUNCTIONS[21].get_arguments(),
+    DEFAULTS_FUNCTIONS[22].get_arguments(),
+    DEFAULTS_FUNCTIONS[23].get_arguments(),
+    DEFAULTS_FUNCTIONS[24].get_arguments(),
+    DEFAULTS_FUNCTIONS[25].get_arguments(),
+    DEFAULTS_FUNCTIONS[26].get_arguments(),
+    DEFAULTS_FUNCTIONS[27].get_arguments(),
+    DEFAULTS_FUNCTIONS[28].get_arguments(),
+    DEFAULTS_FUNCTIONS[29].get_arguments(),
+    DEFAULTS_FUNCTIONS[30].get_arguments(),
+    DEFAULTS_FUNCTIONS[31].get_arguments(),
+    DEFAULTS_FUNCTIONS[32].get_arguments(),
+    DEFAULTS_FUNCTIONS[33].get_arguments(),
+    DEFAULTS_FUNCTIONS[34].get_arguments(),
+    DEFAULTS_FUNCTIONS[35].get_arguments(),
+    DEFAULTS_FUNCTIONS[36].get_arguments(),
+    DEFAULTS_FUNCTIONS[37].get_arguments(),
+    DEFAULTS_FUNCTIONS[38].get_arguments(),
+    DEFAULTS_FUNCTIONS[39].get_arguments(),
+    DEFAULTS_FUNCTIONS[40].get_arguments(),
+    DEFAULTS_FUNCTIONS[41].get_arguments(),
+    DEFAULTS_FUNCTIONS[42].get_arguments(),
+    DEFAULTS_FUNCTIONS[43].get_arguments(),
+    DEFAULTS_FUNCTIONS[44].get_arguments(),
+    DEFAULTS_FUNCTIONS[45].get_arguments(),
+    DEFAULTS_FUNCTIONS[46].get_arguments(),
+    DEFAULTS_FUNCTIONS[47].get_arguments(),
+    DEFAULTS_FUNCTIONS[48].get_arguments(),
+    DEFAULTS_FUNCTIONS[49].get_arguments(),
+    DEFAULTS_FUNCTIONS[50].get_arguments(),
+    DEFAULTS_FUNCTIONS[51].get_arguments(),
+    DEFAULTS_FUNCTIONS[52].get_arguments(),
+    DEFAULTS_FUNCTIONS[53].get_arguments(),
+    DEFAULTS_FUNCTIONS[54].get_arguments(),
+    DEFAULTS_FUNCTIONS[55].get_arguments(),
+    DEFAULTS_FUNCTIONS[56].get_arguments(),
+    DEFAULTS_FUNCTIONS[57].get_arguments(),
+    DEFAULTS_FUNCTIONS[58].get_arguments(),
+    DEFAULTS_FUNCTIONS[59].get_arguments(),
+    DEFAULTS_FUNCTIONS[60].get_arguments(),
+    DEFAULTS_FUNCTIONS[61].get_arguments(),
+    DEFAULTS_FUNCTIONS[62].get_arguments(),
+    DEFAULTS_FUNCTIONS[63].get_arguments(),
+    DEFAULTS_FUNCTIONS[64].get_arguments(),
+    DEFAULTS_FUNCTIONS[65].get_arguments(),
+    DEFAULTS_FUNCTIONS[66].get_arguments(),
+    DEFAULTS_FUNCTIONS[67].get_arguments(),
+    DEFAULTS_FUNCTIONS[68].get_arguments(),
+    DEFAULTS_FUNCTIONS[69].get_arguments(),
+    DEFAULTS_FUNCTIONS[70].get_arguments(),
+    DEFAULTS_FUNCTIONS[71].get_arguments(),
+    DEFAULTS_FUNCTIONS[72].get_arguments(),
+    DEFAULTS_FUNCTIONS[73].get_arguments(),
+    DEFAULTS_FUNCTIONS[74].get_arguments(),
+    DEFAULTS_FUNCTIONS[75].get_arguments(),
+    DEFAULTS_FUNCTIONS[76].get_arguments(),
+    DEFAULTS_FUNCTIONS[77].get_arguments(),
+    DEFAULTS_FUNCTIONS[78].get_arguments(),
+    DEFAULTS_FUNCTIONS[79].get_arguments(),
+    DEFAULTS_FUNCTIONS[80].get_arguments(),
+    DEFAULTS_FUNCTIONS[81].get_arguments(),
+    DEFAULTS_FUNCTIONS[82].get_arguments(),
+    DEFAULTS_FUNCTIONS[83].get_arguments(),
+    DEFAULTS_FUNCTIONS[84].get_arguments(),
+    DEFAULTS_FUNCTIONS[85].get_arguments(),
+    DEFAULTS_FUNCTIONS[86].get_arguments(),
+    DEFAULTS_FUNCTIONS[87].get_arguments(),
+    DEFAULTS_FUNCTIONS[88].get_arguments(),
+    DEFAULTS_FUNCTIONS[89].get_arguments(),
+    DEFAULTS_FUNCTIONS[90].get_arguments(),
+    DEFAULTS_FUNCTIONS[91].get_arguments(),
+    DEFAULTS_FUNCTIONS[92].get_arguments(),
+    DEFAULTS_FUNCTIONS[93].get_arguments(),
+    DEFAULTS_FUNCTIONS[94].get_arguments(),
+    DEFAULTS_FUNCTIONS[95].get_arguments(),
+    DEFAULTS_FUNCTIONS[96].get_arguments(),
+    DEFAULTS_FUNCTIONS[97].get_arguments(),
+    DEFAULTS_FUNCTIONS[98].get_arguments(),
+    DEFAULTS_FUNCTIONS[99].get_arguments(),
+    DEFAULTS_FUNCTIONS[100].get_arguments(),
+    DEFAULTS_FUNCTIONS[101].get_arguments(),
+    DEFAULTS_FUNCTIONS[102].get_arguments(),
+    DEFAULTS_FUNCTIONS[103].get_arguments(),
+    DEFAULTS_FUNCTIONS[104].get_arguments(),
+    DEFAULTS_FUNCTIONS[105].get_arguments(),
+    DEFAULTS_FUNCTIONS[106].get_arguments(),
+    DEFAULTS_FUNCTIONS[107].get_arguments(),
+    DEFAULTS_FUNCTIONS[108].get_arguments(),
+    DEFAULTS_FUNCTIONS[109].get_arguments(),
+    DEFAULTS_FUNCTIONS[110].get_arguments(),
+    DEFAULTS_FUNCTIONS[111].get_arguments(),
+    DEFAULTS_FUNCTIONS[112].get_arguments(),
+    DEFAULTS_FUNCTIONS[113].get_arguments(),
+    DEFAULTS_FUNCTIONS[114].get_arguments(),
+    DEFAULTS_FUNCTIONS[115].get_arguments(),
+    DEFAULTS_FUNCTIONS[116].get_arguments(),
+    DEFAULTS_FUNCTIONS[117].get_arguments(),
+    DEFAULTS_FUNCTIONS[118].get_arguments(),
+    DEFAULTS_FUNCTIONS[119].get_arguments(),
+    DEFAULTS_FUNCTIONS[120].get_arguments(),
+    DEFAULTS_FUNCTIONS[121].get_arguments(),
+    DEFAULTS_FUNCTIONS[122].get_arguments(),
+    DEFAULTS_FUNCTIONS[123].get_arguments(),
+    DEFAULTS_FUNCTIONS[124].get_arguments(),
+    DEFAULTS_FUNCTIONS[125].get_arguments(),
+    DEFAULTS_FUNCTIONS[126].get_arguments(),
+    DEFAULTS_FUNCTIONS[127].get_arguments(),
+    DEFAULTS_FUNCTIONS[128].get_arguments(),
+    DEFAULTS_FUNCTIONS[129].get_arguments(),
+    DEFAULTS_FUNCTIONS[130].get_arguments(),
+    DEFAULTS_FUNCTIONS[131].get_arguments(),
+    DEFAULTS_FUNCTIONS[132].get_arguments(),
+    DEFAULTS_FUNCTIONS[133].get_arguments(),
+    DEFAULTS_FUNCTIONS[134].get_arguments(),
+    DEFAULTS_FUNCTIONS[135].get_arguments(),
+    DEFAULTS_FUNCTIONS[136].get_arguments(),
+    DEFAULTS_FUNCTIONS[137].get_arguments(),
+    DEFAULTS_FUNCTIONS[138].get_arguments(),
+    DEFAULTS_FUNCTIONS[139].get_arguments(),
+    DEFAULTS_FUNCTIONS[140].get_arguments(),
+    DEFAULTS_FUNCTIONS[141].get_arguments(),
+    DEFAULTS_FUNCTIONS[142].get_arguments(),
+    DEFAULTS_FUNCTIONS[143].get_arguments(),
+    DEFAULTS_FUNCTIONS[144].get_arguments(),
+    DEFAULTS_FUNCTIONS[145].get_arguments(),
+    DEFAULTS_FUNCTIONS[146].get_arguments(),
+    DEFAULTS_FUNCTIONS[147].get_arguments(),
+    DEFAULTS_FUNCTIONS[148].get_arguments(),
+    DEFAULTS_FUNCTIONS[149].get_arguments(),
+    DEFAULTS_FUNCTIONS[150].get_arguments(),
+    DEFAULTS_FUNCTIONS[151].get_arguments(),
+    DEFAULTS_FUNCTIONS[152].get_arguments(),
+    DEFAULTS_FUNCTIONS[153].get_arguments(),
+    DEFAULTS_FUNCTIONS[154].get_arguments(),
+    DEFAULTS_FUNCTIONS[155].get_arguments(),
+    DEFAULTS_FUNCTIONS[156].get_arguments(),
+    DEFAULTS_FUNCTIONS[157].get_arguments(),
+    DEFAULTS_FUNCTIONS[158].get_arguments(),
+    DEFAULTS_FUNCTIONS[159].get_arguments(),
+    DEFAULTS_FUNCTIONS[160].get_arguments(),
+    DEFAULTS_FUNCTIONS[161].get_arguments(),
+    DEFAULTS_FUNCTIONS[162].get_arguments(),
+    DEFAULTS_FUNCTIONS[163].get_arguments(),
+    DEFAULTS_FUNCTIONS[164].get_arguments(),
+    DEFAULTS_FUNCTIONS[165].get_arguments(),
+    DEFAULTS_FUNCTIONS[166].get_arguments(),
+    DEFAULTS_FUNCTIONS[167].get_arguments(),
+    DEFAULTS_FUNCTIONS[168].get_arguments(),
+    DEFAULTS_FUNCTIONS[169].get_arguments(),
+    DEFAULTS_FUNCTIONS[170].get_arguments(),
 ];
 
 pub enum DefaultFunction {
@@ -67,6 +572,165 @@ pub enum DefaultFunction {
     Rand,
     Kind,
     Parse,
+    Iter,
+    Next,
+    HasNext,
+    Len,
+    Step,
+    Slice,
+    At,
+    Assert,
+    Split,
+    Join,
+    Replace,
+    Find,
+    StartsWith,
+    EndsWith,
+    Upper,
+    Lower,
+    Trim,
+    Substr,
+    Chars,
+    Repeat,
+    Contains,
+    Abs,
+    Min,
+    Max,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Exp,
+    Ln,
+    Log10,
+    Log,
+    Hypot,
+    Pi,
+    E,
+    Clamp,
+    Gcd,
+    Lcm,
+    Factorial,
+    Modpow,
+    Isqrt,
+    IsPrime,
+    Seed,
+    Randint,
+    Choice,
+    Shuffle,
+    Sample,
+    ReadFile,
+    ReadBytes,
+    WriteFile,
+    AppendFile,
+    ReadLines,
+    Exists,
+    Remove,
+    Mkdir,
+    ListDir,
+    Metadata,
+    Open,
+    Readline,
+    Write,
+    Close,
+    JsonParse,
+    JsonStringify,
+    WsConnect,
+    WsSend,
+    WsRecv,
+    WsClose,
+    WsListen,
+    CpuCount,
+    CpuUsage,
+    Memory,
+    Swap,
+    LoadAverage,
+    Disks,
+    Processes,
+    Pid,
+    Now,
+    Monotonic,
+    Sleep,
+    Date,
+    Strftime,
+    Timeit,
+    Env,
+    SetEnv,
+    EnvAll,
+    Exec,
+    ExecStream,
+    Exit,
+    ReMatch,
+    ReFindAll,
+    ReReplace,
+    ReSplit,
+    Eprint,
+    Format,
+    Input,
+    ReadAll,
+    ReadInt,
+    ReadFloat,
+    Str,
+    Float,
+    Bool,
+    Bigint,
+    ToRadix,
+    ToTuple,
+    Repr,
+    Push,
+    Pop,
+    Concat,
+    Reverse,
+    Sort,
+    Sorted,
+    Unique,
+    IndexOf,
+    Sum,
+    Zip,
+    Enumerate,
+    Map,
+    Filter,
+    Reduce,
+    TermSize,
+    Clear,
+    MoveCursor,
+    Color,
+    Bold,
+    Underline,
+    ShowCursor,
+    HideCursor,
+    Getkey,
+    Base64Encode,
+    Base64Decode,
+    HexEncode,
+    HexDecode,
+    UrlEncode,
+    UrlDecode,
+    Utf8Bytes,
+    FromUtf8,
+    Crc32,
+    Md5,
+    Sha1,
+    Sha256,
+    Hash,
+    CsvParse,
+    CsvStringify,
+    CsvRows,
+    TcpConnect,
+    TcpListen,
+    Accept,
+    Send,
+    Recv,
+    UdpBind,
+    UdpSendTo,
+    UdpRecvFrom,
+    LocalAddr,
+    HttpGet,
+    HttpRequest,
+    HttpServe,
+    StdinLines,
 }
 
 impl DefaultFunction {
@@ -94,6 +758,165 @@ impl DefaultFunction {
             Self::Rand => "rand()",
             Self::Kind => "kind()",
             Self::Parse => "parse()",
+            Self::Iter => "iter()",
+            Self::Next => "next()",
+            Self::HasNext => "has_next()",
+            Self::Len => "len()",
+            Self::Step => "step()",
+            Self::Slice => "slice()",
+            Self::At => "at()",
+            Self::Assert => "assert()",
+            Self::Split => "split()",
+            Self::Join => "join()",
+            Self::Replace => "replace()",
+            Self::Find => "find()",
+            Self::StartsWith => "starts_with()",
+            Self::EndsWith => "ends_with()",
+            Self::Upper => "upper()",
+            Self::Lower => "lower()",
+            Self::Trim => "trim()",
+            Self::Substr => "substr()",
+            Self::Chars => "chars()",
+            Self::Repeat => "repeat()",
+            Self::Contains => "contains()",
+            Self::Abs => "abs()",
+            Self::Min => "min()",
+            Self::Max => "max()",
+            Self::Sin => "sin()",
+            Self::Cos => "cos()",
+            Self::Tan => "tan()",
+            Self::Asin => "asin()",
+            Self::Acos => "acos()",
+            Self::Atan => "atan()",
+            Self::Exp => "exp()",
+            Self::Ln => "ln()",
+            Self::Log10 => "log10()",
+            Self::Log => "log()",
+            Self::Hypot => "hypot()",
+            Self::Pi => "pi()",
+            Self::E => "e()",
+            Self::Clamp => "clamp()",
+            Self::Gcd => "gcd()",
+            Self::Lcm => "lcm()",
+            Self::Factorial => "factorial()",
+            Self::Modpow => "modpow()",
+            Self::Isqrt => "isqrt()",
+            Self::IsPrime => "is_prime()",
+            Self::Seed => "seed()",
+            Self::Randint => "randint()",
+            Self::Choice => "choice()",
+            Self::Shuffle => "shuffle()",
+            Self::Sample => "sample()",
+            Self::ReadFile => "read_file()",
+            Self::ReadBytes => "read_bytes()",
+            Self::WriteFile => "write_file()",
+            Self::AppendFile => "append_file()",
+            Self::ReadLines => "read_lines()",
+            Self::Exists => "exists()",
+            Self::Remove => "remove()",
+            Self::Mkdir => "mkdir()",
+            Self::ListDir => "list_dir()",
+            Self::Metadata => "metadata()",
+            Self::Open => "open()",
+            Self::Readline => "readline()",
+            Self::Write => "write()",
+            Self::Close => "close()",
+            Self::JsonParse => "json_parse()",
+            Self::JsonStringify => "json_stringify()",
+            Self::WsConnect => "ws_connect()",
+            Self::WsSend => "ws_send()",
+            Self::WsRecv => "ws_recv()",
+            Self::WsClose => "ws_close()",
+            Self::WsListen => "ws_listen()",
+            Self::CpuCount => "cpu_count()",
+            Self::CpuUsage => "cpu_usage()",
+            Self::Memory => "memory()",
+            Self::Swap => "swap()",
+            Self::LoadAverage => "load_average()",
+            Self::Disks => "disks()",
+            Self::Processes => "processes()",
+            Self::Pid => "pid()",
+            Self::Now => "now()",
+            Self::Monotonic => "monotonic()",
+            Self::Sleep => "sleep()",
+            Self::Date => "date()",
+            Self::Strftime => "strftime()",
+            Self::Timeit => "timeit()",
+            Self::Env => "env()",
+            Self::SetEnv => "set_env()",
+            Self::EnvAll => "env_all()",
+            Self::Exec => "exec()",
+            Self::ExecStream => "exec_stream()",
+            Self::Exit => "exit()",
+            Self::ReMatch => "re_match()",
+            Self::ReFindAll => "re_find_all()",
+            Self::ReReplace => "re_replace()",
+            Self::ReSplit => "re_split()",
+            Self::Eprint => "eprint()",
+            Self::Format => "format()",
+            Self::Input => "input()",
+            Self::ReadAll => "read_all()",
+            Self::ReadInt => "read_int()",
+            Self::ReadFloat => "read_float()",
+            Self::Str => "str()",
+            Self::Float => "float()",
+            Self::Bool => "bool()",
+            Self::Bigint => "bigint()",
+            Self::ToRadix => "to_radix()",
+            Self::ToTuple => "tuple()",
+            Self::Repr => "repr()",
+            Self::Push => "push()",
+            Self::Pop => "pop()",
+            Self::Concat => "concat()",
+            Self::Reverse => "reverse()",
+            Self::Sort => "sort()",
+            Self::Sorted => "sorted()",
+            Self::Unique => "unique()",
+            Self::IndexOf => "index_of()",
+            Self::Sum => "sum()",
+            Self::Zip => "zip()",
+            Self::Enumerate => "enumerate()",
+            Self::Map => "map()",
+            Self::Filter => "filter()",
+            Self::Reduce => "reduce()",
+            Self::TermSize => "term_size()",
+            Self::Clear => "clear()",
+            Self::MoveCursor => "move_cursor()",
+            Self::Color => "color()",
+            Self::Bold => "bold()",
+            Self::Underline => "underline()",
+            Self::ShowCursor => "show_cursor()",
+            Self::HideCursor => "hide_cursor()",
+            Self::Getkey => "getkey()",
+            Self::Base64Encode => "base64_encode()",
+            Self::Base64Decode => "base64_decode()",
+            Self::HexEncode => "hex_encode()",
+            Self::HexDecode => "hex_decode()",
+            Self::UrlEncode => "url_encode()",
+            Self::UrlDecode => "url_decode()",
+            Self::Utf8Bytes => "utf8_bytes()",
+            Self::FromUtf8 => "from_utf8()",
+            Self::Crc32 => "crc32()",
+            Self::Md5 => "md5()",
+            Self::Sha1 => "sha1()",
+            Self::Sha256 => "sha256()",
+            Self::Hash => "hash()",
+            Self::CsvParse => "csv_parse()",
+            Self::CsvStringify => "csv_stringify()",
+            Self::CsvRows => "csv_rows()",
+            Self::TcpConnect => "tcp_connect()",
+            Self::TcpListen => "tcp_listen()",
+            Self::Accept => "accept()",
+            Self::Send => "send()",
+            Self::Recv => "recv()",
+            Self::UdpBind => "udp_bind()",
+            Self::UdpSendTo => "udp_send_to()",
+            Self::UdpRecvFrom => "udp_recv_from()",
+            Self::LocalAddr => "local_addr()",
+            Self::HttpGet => "http_get()",
+            Self::HttpRequest => "http_request()",
+            Self::HttpServe => "http_serve()",
+            Self::StdinLines => "stdin_lines()",
         }
     }
 
@@ -111,10 +934,179 @@ impl DefaultFunction {
             Self::Rand => &RAND_ARGS,
             Self::Kind => &KIND_ARGS,
             Self::Parse => &PARSE_ARGS,
+            Self::Iter => &ITER_ARGS,
+            Self::Next => &NEXT_ARGS,
+            Self::HasNext => &HAS_NEXT_ARGS,
+            Self::Len => &LEN_ARGS,
+            Self::Step => &STEP_ARGS,
+            Self::Slice => &SLICE_ARGS,
+            Self::At => &AT_ARGS,
+            Self::Assert => &ASSERT_ARGS,
+            Self::Split => &SPLIT_ARGS,
+            Self::Join => &JOIN_ARGS,
+            Self::Replace => &REPLACE_ARGS,
+            Self::Find => &FIND_ARGS,
+            Self::StartsWith => &STARTS_WITH_ARGS,
+            Self::EndsWith => &ENDS_WITH_ARGS,
+            Self::Upper => &UPPER_ARGS,
+            Self::Lower => &LOWER_ARGS,
+            Self::Trim => &TRIM_ARGS,
+            Self::Substr => &SUBSTR_ARGS,
+            Self::Chars => &CHARS_ARGS,
+            Self::Repeat => &REPEAT_ARGS,
+            Self::Contains => &CONTAINS_ARGS,
+            Self::Abs => &ABS_ARGS,
+            Self::Min => &MIN_ARGS,
+            Self::Max => &MAX_ARGS,
+            Self::Sin => &SIN_ARGS,
+            Self::Cos => &COS_ARGS,
+            Self::Tan => &TAN_ARGS,
+            Self::Asin => &ASIN_ARGS,
+            Self::Acos => &ACOS_ARGS,
+            Self::Atan => &ATAN_ARGS,
+            Self::Exp => &EXP_ARGS,
+            Self::Ln => &LN_ARGS,
+            Self::Log10 => &LOG10_ARGS,
+            Self::Log => &LOG_ARGS,
+            Self::Hypot => &HYPOT_ARGS,
+            Self::Pi => &PI_ARGS,
+            Self::E => &E_ARGS,
+            Self::Clamp => &CLAMP_ARGS,
+            Self::Gcd => &GCD_ARGS,
+            Self::Lcm => &LCM_ARGS,
+            Self::Factorial => &FACTORIAL_ARGS,
+            Self::Modpow => &MODPOW_ARGS,
+            Self::Isqrt => &ISQRT_ARGS,
+            Self::IsPrime => &IS_PRIME_ARGS,
+            Self::Seed => &SEED_ARGS,
+            Self::Randint => &RANDINT_ARGS,
+            Self::Choice => &CHOICE_ARGS,
+            Self::Shuffle => &SHUFFLE_ARGS,
+            Self::Sample => &SAMPLE_ARGS,
+            Self::ReadFile => &READ_FILE_ARGS,
+            Self::ReadBytes => &READ_BYTES_ARGS,
+            Self::WriteFile => &WRITE_FILE_ARGS,
+            Self::AppendFile => &APPEND_FILE_ARGS,
+            Self::ReadLines => &READ_LINES_ARGS,
+            Self::Exists => &EXISTS_ARGS,
+            Self::Remove => &REMOVE_ARGS,
+            Self::Mkdir => &MKDIR_ARGS,
+            Self::ListDir => &LIST_DIR_ARGS,
+            Self::Metadata => &METADATA_ARGS,
+            Self::Open => &OPEN_ARGS,
+            Self::Readline => &READLINE_ARGS,
+            Self::Write => &WRITE_ARGS,
+            Self::Close => &CLOSE_ARGS,
+            Self::JsonParse => &JSON_PARSE_ARGS,
+            Self::JsonStringify => &JSON_STRINGIFY_ARGS,
+            Self::WsConnect => &WS_CONNECT_ARGS,
+            Self::WsSend => &WS_SEND_ARGS,
+            Self::WsRecv => &WS_RECV_ARGS,
+            Self::WsClose => &WS_CLOSE_ARGS,
+            Self::WsListen => &WS_LISTEN_ARGS,
+            Self::CpuCount => &CPU_COUNT_ARGS,
+            Self::CpuUsage => &CPU_USAGE_ARGS,
+            Self::Memory => &MEMORY_ARGS,
+            Self::Swap => &SWAP_ARGS,
+            Self::LoadAverage => &LOAD_AVERAGE_ARGS,
+            Self::Disks => &DISKS_ARGS,
+            Self::Processes => &PROCESSES_ARGS,
+            Self::Pid => &PID_ARGS,
+            Self::Now => &NOW_ARGS,
+            Self::Monotonic => &MONOTONIC_ARGS,
+            Self::Sleep => &SLEEP_ARGS,
+            Self::Date => &DATE_ARGS,
+            Self::Strftime => &STRFTIME_ARGS,
+            Self::Timeit => &TIMEIT_ARGS,
+            Self::Env => &ENV_ARGS,
+            Self::SetEnv => &SET_ENV_ARGS,
+            Self::EnvAll => &ENV_ALL_ARGS,
+            Self::Exec => &EXEC_ARGS,
+            Self::ExecStream => &EXEC_STREAM_ARGS,
+            Self::Exit => &EXIT_ARGS,
+            Self::ReMatch => &RE_MATCH_ARGS,
+            Self::ReFindAll => &RE_FIND_ALL_ARGS,
+            Self::ReReplace => &RE_REPLACE_ARGS,
+            Self::ReSplit => &RE_SPLIT_ARGS,
+            Self::Eprint => &EPRINT_ARGS,
+            Self::Format => &FORMAT_ARGS,
+            Self::Input => &INPUT_ARGS,
+            Self::ReadAll => &READ_ALL_ARGS,
+            Self::ReadInt => &READ_INT_ARGS,
+            Self::ReadFloat => &READ_FLOAT_ARGS,
+            Self::Str => &STR_ARGS,
+            Self::Float => &FLOAT_ARGS,
+            Self::Bool => &BOOL_ARGS,
+            Self::Bigint => &BIGINT_ARGS,
+            Self::ToRadix => &TO_RADIX_ARGS,
+            Self::ToTuple => &TUPLE_ARGS,
+            Self::Repr => &REPR_ARGS,
+            Self::Push => &PUSH_ARGS,
+            Self::Pop => &POP_ARGS,
+            Self::Concat => &CONCAT_ARGS,
+            Self::Reverse => &REVERSE_ARGS,
+            Self::Sort => &SORT_ARGS,
+            Self::Sorted => &SORTED_ARGS,
+            Self::Unique => &UNIQUE_ARGS,
+            Self::IndexOf => &INDEX_OF_ARGS,
+            Self::Sum => &SUM_ARGS,
+            Self::Zip => &ZIP_ARGS,
+            Self::Enumerate => &ENUMERATE_FN_ARGS,
+            Self::Map => &MAP_ARGS,
+            Self::Filter => &FILTER_ARGS,
+            Self::Reduce => &REDUCE_ARGS,
+            Self::TermSize => &TERM_SIZE_ARGS,
+            Self::Clear => &CLEAR_ARGS,
+            Self::MoveCursor => &MOVE_CURSOR_ARGS,
+            Self::Color => &COLOR_ARGS,
+            Self::Bold => &BOLD_ARGS,
+            Self::Underline => &UNDERLINE_ARGS,
+            Self::ShowCursor => &SHOW_CURSOR_ARGS,
+            Self::HideCursor => &HIDE_CURSOR_ARGS,
+            Self::Getkey => &GETKEY_ARGS,
+            Self::Base64Encode => &BASE64_ENCODE_ARGS,
+            Self::Base64Decode => &BASE64_DECODE_ARGS,
+            Self::HexEncode => &HEX_ENCODE_ARGS,
+            Self::HexDecode => &HEX_DECODE_ARGS,
+            Self::UrlEncode => &URL_ENCODE_ARGS,
+            Self::UrlDecode => &URL_DECODE_ARGS,
+            Self::Utf8Bytes => &UTF8_BYTES_ARGS,
+            Self::FromUtf8 => &FROM_UTF8_ARGS,
+            Self::Crc32 => &CRC32_ARGS,
+            Self::Md5 => &MD5_ARGS,
+            Self::Sha1 => &SHA1_ARGS,
+            Self::Sha256 => &SHA256_ARGS,
+            Self::Hash => &HASH_ARGS,
+            Self::CsvParse => &CSV_PARSE_ARGS,
+            Self::CsvStringify => &CSV_STRINGIFY_ARGS,
+            Self::CsvRows => &CSV_ROWS_ARGS,
+            Self::TcpConnect => &TCP_CONNECT_ARGS,
+            Self::TcpListen => &TCP_LISTEN_ARGS,
+            Self::Accept => &ACCEPT_ARGS,
+            Self::Send => &SEND_ARGS,
+            Self::Recv => &RECV_ARGS,
+            Self::UdpBind => &UDP_BIND_ARGS,
+            Self::UdpSendTo => &UDP_SEND_TO_ARGS,
+            Self::UdpRecvFrom => &UDP_RECV_FROM_ARGS,
+            Self::LocalAddr => &LOCAL_ADDR_ARGS,
+            Self::HttpGet => &HTTP_GET_ARGS,
+            Self::HttpRequest => &HTTP_REQUEST_ARGS,
+            Self::HttpServe => &HTTP_SERVE_ARGS,
+            Self::StdinLines => &STDIN_LINES_ARGS,
         }
     }
 
-    pub fn run(&self, vec_table: &mut VecTable) -> Tuple {
+    pub fn get_pos(&self) -> usize {
+        for i in 0..(DEFAULTS_FUNCTIONS.len()) {
+            if DEFAULTS_FUNCTIONS[i] == *self {
+                return i;
+            }
+        }
+
+        return DEFAULTS_FUNCTIONS.len(); // meant to make a error because it isn't supposed to get here
+    }
+
+    pub fn run(&self, vec_table: &mut VecTable, process: &Process) -> Tuple {
         match self {
             Self::Pause => pause(),
             Self::Read => read(),
@@ -128,6 +1120,174 @@ impl DefaultFunction {
             Self::Rand => rand(vec_table),
             Self::Kind => kind(vec_table),
             Self::Parse => parse(vec_table),
+            Self::Iter => iter(vec_table),
+            Self::Next => next(vec_table),
+            Self::HasNext => has_next(vec_table),
+            Self::Len => len(vec_table),
+            Self::Step => step(vec_table),
+            Self::Slice => slice(vec_table),
+            Self::At => at(vec_table),
+            Self::Assert => assert(vec_table),
+            Self::Split => split(vec_table),
+            Self::Join => join(vec_table),
+            Self::Replace => replace(vec_table),
+            Self::Find => find(vec_table),
+            Self::StartsWith => starts_with(vec_table),
+            Self::EndsWith => ends_with(vec_table),
+            Self::Upper => upper(vec_table),
+            Self::Lower => lower(vec_table),
+            Self::Trim => trim(vec_table),
+            Self::Substr => substr(vec_table),
+            Self::Chars => chars(vec_table),
+            Self::Repeat => repeat(vec_table),
+            Self::Contains => contains(vec_table),
+            Self::Abs => abs(vec_table),
+            Self::Min => min(vec_table),
+            Self::Max => max(vec_table),
+            Self::Sin => sin(vec_table),
+            Self::Cos => cos(vec_table),
+            Self::Tan => tan(vec_table),
+            Self::Asin => asin(vec_table),
+            Self::Acos => acos(vec_table),
+            Self::Atan => atan(vec_table),
+            Self::Exp => exp(vec_table),
+            Self::Ln => ln(vec_table),
+            Self::Log10 => log10(vec_table),
+            Self::Log => log(vec_table),
+            Self::Hypot => hypot(vec_table),
+            Self::Pi => pi(vec_table),
+            Self::E => e(vec_table),
+            Self::Clamp => clamp(vec_table),
+            Self::Gcd => gcd(vec_table),
+            Self::Lcm => lcm(vec_table),
+            Self::Factorial => factorial(vec_table),
+            Self::Modpow => modpow(vec_table),
+            Self::Isqrt => isqrt(vec_table),
+            Self::IsPrime => is_prime(vec_table),
+            Self::Seed => seed(vec_table),
+            Self::Randint => randint(vec_table),
+            Self::Choice => choice(vec_table),
+            Self::Shuffle => shuffle(vec_table),
+            Self::Sample => sample(vec_table),
+            Self::ReadFile => read_file(vec_table),
+            Self::ReadBytes => read_bytes(vec_table),
+            Self::WriteFile => write_file(vec_table),
+            Self::AppendFile => append_file(vec_table),
+            Self::ReadLines => read_lines(vec_table),
+            Self::Exists => exists(vec_table),
+            Self::Remove => remove(vec_table),
+            Self::Mkdir => mkdir(vec_table),
+            Self::ListDir => list_dir(vec_table),
+            Self::Metadata => metadata(vec_table),
+            Self::Open => open(vec_table),
+            Self::Readline => readline(vec_table),
+            Self::Write => write(vec_table),
+            Self::Close => close(vec_table),
+            Self::JsonParse => json_parse(vec_table),
+            Self::JsonStringify => json_stringify(vec_table),
+            Self::WsConnect => ws_connect(vec_table),
+            Self::WsSend => ws_send(vec_table),
+            Self::WsRecv => ws_recv(vec_table),
+            Self::WsClose => ws_close(vec_table),
+            Self::WsListen => ws_listen(vec_table, process),
+            Self::CpuCount => cpu_count(),
+            Self::CpuUsage => cpu_usage(),
+            Self::Memory => memory(),
+            Self::Swap => swap(),
+            Self::LoadAverage => load_average(),
+            Self::Disks => disks(),
+            Self::Processes => processes(),
+            Self::Pid => pid(),
+            Self::Now => now(),
+            Self::Monotonic => monotonic(vec_table),
+            Self::Sleep => sleep(vec_table),
+            Self::Date => date(vec_table),
+            Self::Strftime => strftime(vec_table),
+            Self::Timeit => timeit(vec_table, process),
+            Self::Env => env(vec_table),
+            Self::SetEnv => set_env(vec_table),
+            Self::EnvAll => env_all(),
+            Self::Exec => exec(vec_table),
+            Self::ExecStream => exec_stream(vec_table),
+            Self::Exit => exit(vec_table),
+            Self::ReMatch => re_match(vec_table),
+            Self::ReFindAll => re_find_all(vec_table),
+            Self::ReReplace => re_replace(vec_table, process),
+            Self::ReSplit => re_split(vec_table),
+            Self::Eprint => eprint(vec_table),
+            Self::Format => format(vec_table),
+            Self::Input => input(vec_table),
+            Self::ReadAll => read_all(vec_table),
+            Self::ReadInt => read_int(vec_table),
+            Self::ReadFloat => read_float(vec_table),
+            Self::Str => str(vec_table),
+            Self::Float => float(vec_table),
+            Self::Bool => bool(vec_table),
+            Self::Bigint => bigint(vec_table),
+            Self::ToRadix => to_radix(vec_table),
+            Self::ToTuple => tuple(vec_table),
+            Self::Repr => repr(vec_table),
+            Self::Push => push(vec_table),
+            Self::Pop => pop(vec_table),
+            Self::Concat => concat(vec_table),
+            Self::Reverse => reverse(vec_table),
+            Self::Sort => sort(vec_table, process),
+            Self::Sorted => sorted(vec_table, process),
+            Self::Unique => unique(vec_table),
+            Self::IndexOf => index_of(vec_table),
+            Self::Sum => sum(vec_table),
+            Self::Zip => zip(vec_table),
+            Self::Enumerate => enumerate(vec_table),
+            Self::Map => map(vec_table, process),
+            Self::Filter => filter(vec_table, process),
+            Self::Reduce => reduce(vec_table, process),
+            Self::TermSize => term_size(vec_table),
+            #[cfg(target_family = "unix")]
+            Self::Clear => clear(),
+            #[cfg(target_family = "windows")]
+            Self::Clear => clear(vec_table),
+            Self::MoveCursor => move_cursor(vec_table),
+            Self::Color => color(vec_table),
+            Self::Bold => bold(vec_table),
+            Self::Underline => underline(vec_table),
+            #[cfg(target_family = "unix")]
+            Self::ShowCursor => show_cursor(),
+            #[cfg(target_family = "windows")]
+            Self::ShowCursor => show_cursor(vec_table),
+            #[cfg(target_family = "unix")]
+            Self::HideCursor => hide_cursor(),
+            #[cfg(target_family = "windows")]
+            Self::HideCursor => hide_cursor(vec_table),
+            Self::Getkey => getkey(vec_table),
+            Self::Base64Encode => base64_encode(vec_table),
+            Self::Base64Decode => base64_decode(vec_table),
+            Self::HexEncode => hex_encode(vec_table),
+            Self::HexDecode => hex_decode(vec_table),
+            Self::UrlEncode => url_encode(vec_table),
+            Self::UrlDecode => url_decode(vec_table),
+            Self::Utf8Bytes => utf8_bytes(vec_table),
+            Self::FromUtf8 => from_utf8(vec_table),
+            Self::Crc32 => crc32(vec_table),
+            Self::Md5 => md5(vec_table),
+            Self::Sha1 => sha1(vec_table),
+            Self::Sha256 => sha256(vec_table),
+            Self::Hash => hash(vec_table),
+            Self::CsvParse => csv_parse(vec_table),
+            Self::CsvStringify => csv_stringify(vec_table),
+            Self::CsvRows => csv_rows(vec_table),
+            Self::TcpConnect => tcp_connect(vec_table),
+            Self::TcpListen => tcp_listen(vec_table),
+            Self::Accept => accept(vec_table),
+            Self::Send => send(vec_table),
+            Self::Recv => recv(vec_table),
+            Self::UdpBind => udp_bind(vec_table),
+            Self::UdpSendTo => udp_send_to(vec_table),
+            Self::UdpRecvFrom => udp_recv_from(vec_table),
+            Self::LocalAddr => local_addr(vec_table),
+            Self::HttpGet => http_get(vec_table),
+            Self::HttpRequest => http_request(vec_table),
+            Self::HttpServe => http_serve(vec_table, process),
+            Self::StdinLines => stdin_lines(vec_table),
         }
     }
 }
@@ -153,6 +1313,165 @@ impl std::cmp::PartialEq for DefaultFunction {
             Self::Rand => matches!(other, Self::Rand),
             Self::Kind => matches!(other, Self::Kind),
             Self::Parse => matches!(other, Self::Parse),
+            Self::Iter => matches!(other, Self::Iter),
+            Self::Next => matches!(other, Self::Next),
+            Self::HasNext => matches!(other, Self::HasNext),
+            Self::Len => matches!(other, Self::Len),
+            Self::Step => matches!(other, Self::Step),
+            Self::Slice => matches!(other, Self::Slice),
+            Self::At => matches!(other, Self::At),
+            Self::Assert => matches!(other, Self::Assert),
+            Self::Split => matches!(other, Self::Split),
+            Self::Join => matches!(other, Self::Join),
+            Self::Replace => matches!(other, Self::Replace),
+            Self::Find => matches!(other, Self::Find),
+            Self::StartsWith => matches!(other, Self::StartsWith),
+            Self::EndsWith => matches!(other, Self::EndsWith),
+            Self::Upper => matches!(other, Self::Upper),
+            Self::Lower => matches!(other, Self::Lower),
+            Self::Trim => matches!(other, Self::Trim),
+            Self::Substr => matches!(other, Self::Substr),
+            Self::Chars => matches!(other, Self::Chars),
+            Self::Repeat => matches!(other, Self::Repeat),
+            Self::Contains => matches!(other, Self::Contains),
+            Self::Abs => matches!(other, Self::Abs),
+            Self::Min => matches!(other, Self::Min),
+            Self::Max => matches!(other, Self::Max),
+            Self::Sin => matches!(other, Self::Sin),
+            Self::Cos => matches!(other, Self::Cos),
+            Self::Tan => matches!(other, Self::Tan),
+            Self::Asin => matches!(other, Self::Asin),
+            Self::Acos => matches!(other, Self::Acos),
+            Self::Atan => matches!(other, Self::Atan),
+            Self::Exp => matches!(other, Self::Exp),
+            Self::Ln => matches!(other, Self::Ln),
+            Self::Log10 => matches!(other, Self::Log10),
+            Self::Log => matches!(other, Self::Log),
+            Self::Hypot => matches!(other, Self::Hypot),
+            Self::Pi => matches!(other, Self::Pi),
+            Self::E => matches!(other, Self::E),
+            Self::Clamp => matches!(other, Self::Clamp),
+            Self::Gcd => matches!(other, Self::Gcd),
+            Self::Lcm => matches!(other, Self::Lcm),
+            Self::Factorial => matches!(other, Self::Factorial),
+            Self::Modpow => matches!(other, Self::Modpow),
+            Self::Isqrt => matches!(other, Self::Isqrt),
+            Self::IsPrime => matches!(other, Self::IsPrime),
+            Self::Seed => matches!(other, Self::Seed),
+            Self::Randint => matches!(other, Self::Randint),
+            Self::Choice => matches!(other, Self::Choice),
+            Self::Shuffle => matches!(other, Self::Shuffle),
+            Self::Sample => matches!(other, Self::Sample),
+            Self::ReadFile => matches!(other, Self::ReadFile),
+            Self::ReadBytes => matches!(other, Self::ReadBytes),
+            Self::WriteFile => matches!(other, Self::WriteFile),
+            Self::AppendFile => matches!(other, Self::AppendFile),
+            Self::ReadLines => matches!(other, Self::ReadLines),
+            Self::Exists => matches!(other, Self::Exists),
+            Self::Remove => matches!(other, Self::Remove),
+            Self::Mkdir => matches!(other, Self::Mkdir),
+            Self::ListDir => matches!(other, Self::ListDir),
+            Self::Metadata => matches!(other, Self::Metadata),
+            Self::Open => matches!(other, Self::Open),
+            Self::Readline => matches!(other, Self::Readline),
+            Self::Write => matches!(other, Self::Write),
+            Self::Close => matches!(other, Self::Close),
+            Self::JsonParse => matches!(other, Self::JsonParse),
+            Self::JsonStringify => matches!(other, Self::JsonStringify),
+            Self::WsConnect => matches!(other, Self::WsConnect),
+            Self::WsSend => matches!(other, Self::WsSend),
+            Self::WsRecv => matches!(other, Self::WsRecv),
+            Self::WsClose => matches!(other, Self::WsClose),
+            Self::WsListen => matches!(other, Self::WsListen),
+            Self::CpuCount => matches!(other, Self::CpuCount),
+            Self::CpuUsage => matches!(other, Self::CpuUsage),
+            Self::Memory => matches!(other, Self::Memory),
+            Self::Swap => matches!(other, Self::Swap),
+            Self::LoadAverage => matches!(other, Self::LoadAverage),
+            Self::Disks => matches!(other, Self::Disks),
+            Self::Processes => matches!(other, Self::Processes),
+            Self::Pid => matches!(other, Self::Pid),
+            Self::Now => matches!(other, Self::Now),
+            Self::Monotonic => matches!(other, Self::Monotonic),
+            Self::Sleep => matches!(other, Self::Sleep),
+            Self::Date => matches!(other, Self::Date),
+            Self::Strftime => matches!(other, Self::Strftime),
+            Self::Timeit => matches!(other, Self::Timeit),
+            Self::Env => matches!(other, Self::Env),
+            Self::SetEnv => matches!(other, Self::SetEnv),
+            Self::EnvAll => matches!(other, Self::EnvAll),
+            Self::Exec => matches!(other, Self::Exec),
+            Self::ExecStream => matches!(other, Self::ExecStream),
+            Self::Exit => matches!(other, Self::Exit),
+            Self::ReMatch => matches!(other, Self::ReMatch),
+            Self::ReFindAll => matches!(other, Self::ReFindAll),
+            Self::ReReplace => matches!(other, Self::ReReplace),
+            Self::ReSplit => matches!(other, Self::ReSplit),
+            Self::Eprint => matches!(other, Self::Eprint),
+            Self::Format => matches!(other, Self::Format),
+            Self::Input => matches!(other, Self::Input),
+            Self::ReadAll => matches!(other, Self::ReadAll),
+            Self::ReadInt => matches!(other, Self::ReadInt),
+            Self::ReadFloat => matches!(other, Self::ReadFloat),
+            Self::Str => matches!(other, Self::Str),
+            Self::Float => matches!(other, Self::Float),
+            Self::Bool => matches!(other, Self::Bool),
+            Self::Bigint => matches!(other, Self::Bigint),
+            Self::ToRadix => matches!(other, Self::ToRadix),
+            Self::ToTuple => matches!(other, Self::ToTuple),
+            Self::Repr => matches!(other, Self::Repr),
+            Self::Push => matches!(other, Self::Push),
+            Self::Pop => matches!(other, Self::Pop),
+            Self::Concat => matches!(other, Self::Concat),
+            Self::Reverse => matches!(other, Self::Reverse),
+            Self::Sort => matches!(other, Self::Sort),
+            Self::Sorted => matches!(other, Self::Sorted),
+            Self::Unique => matches!(other, Self::Unique),
+            Self::IndexOf => matches!(other, Self::IndexOf),
+            Self::Sum => matches!(other, Self::Sum),
+            Self::Zip => matches!(other, Self::Zip),
+            Self::Enumerate => matches!(other, Self::Enumerate),
+            Self::Map => matches!(other, Self::Map),
+            Self::Filter => matches!(other, Self::Filter),
+            Self::Reduce => matches!(other, Self::Reduce),
+            Self::TermSize => matches!(other, Self::TermSize),
+            Self::Clear => matches!(other, Self::Clear),
+            Self::MoveCursor => matches!(other, Self::MoveCursor),
+            Self::Color => matches!(other, Self::Color),
+            Self::Bold => matches!(other, Self::Bold),
+            Self::Underline => matches!(other, Self::Underline),
+            Self::ShowCursor => matches!(other, Self::ShowCursor),
+            Self::HideCursor => matches!(other, Self::HideCursor),
+            Self::Getkey => matches!(other, Self::Getkey),
+            Self::Base64Encode => matches!(other, Self::Base64Encode),
+            Self::Base64Decode => matches!(other, Self::Base64Decode),
+            Self::HexEncode => matches!(other, Self::HexEncode),
+            Self::HexDecode => matches!(other, Self::HexDecode),
+            Self::UrlEncode => matches!(other, Self::UrlEncode),
+            Self::UrlDecode => matches!(other, Self::UrlDecode),
+            Self::Utf8Bytes => matches!(other, Self::Utf8Bytes),
+            Self::FromUtf8 => matches!(other, Self::FromUtf8),
+            Self::Crc32 => matches!(other, Self::Crc32),
+            Self::Md5 => matches!(other, Self::Md5),
+            Self::Sha1 => matches!(other, Self::Sha1),
+            Self::Sha256 => matches!(other, Self::Sha256),
+            Self::Hash => matches!(other, Self::Hash),
+            Self::CsvParse => matches!(other, Self::CsvParse),
+            Self::CsvStringify => matches!(other, Self::CsvStringify),
+            Self::CsvRows => matches!(other, Self::CsvRows),
+            Self::TcpConnect => matches!(other, Self::TcpConnect),
+            Self::TcpListen => matches!(other, Self::TcpListen),
+            Self::Accept => matches!(other, Self::Accept),
+            Self::Send => matches!(other, Self::Send),
+            Self::Recv => matches!(other, Self::Recv),
+            Self::UdpBind => matches!(other, Self::UdpBind),
+            Self::UdpSendTo => matches!(other, Self::UdpSendTo),
+            Self::UdpRecvFrom => matches!(other, Self::UdpRecvFrom),
+            Self::LocalAddr => matches!(other, Self::LocalAddr),
+            Self::HttpGet => matches!(other, Self::HttpGet),
+            Self::HttpRequest => matches!(other, Self::HttpRequest),
+            Self::HttpServe => matches!(other, Self::HttpServe),
+            Self::StdinLines => matches!(other, Self::StdinLines),
         }
     }
 }
@@ -172,6 +1491,165 @@ impl Clone for DefaultFunction {
             Self::Rand => Self::Rand,
             Self::Kind => Self::Kind,
             Self::Parse => Self::Parse,
+            Self::Iter => Self::Iter,
+            Self::Next => Self::Next,
+            Self::HasNext => Self::HasNext,
+            Self::Len => Self::Len,
+            Self::Step => Self::Step,
+            Self::Slice => Self::Slice,
+            Self::At => Self::At,
+            Self::Assert => Self::Assert,
+            Self::Split => Self::Split,
+            Self::Join => Self::Join,
+            Self::Replace => Self::Replace,
+            Self::Find => Self::Find,
+            Self::StartsWith => Self::StartsWith,
+            Self::EndsWith => Self::EndsWith,
+            Self::Upper => Self::Upper,
+            Self::Lower => Self::Lower,
+            Self::Trim => Self::Trim,
+            Self::Substr => Self::Substr,
+            Self::Chars => Self::Chars,
+            Self::Repeat => Self::Repeat,
+            Self::Contains => Self::Contains,
+            Self::Abs => Self::Abs,
+            Self::Min => Self::Min,
+            Self::Max => Self::Max,
+            Self::Sin => Self::Sin,
+            Self::Cos => Self::Cos,
+            Self::Tan => Self::Tan,
+            Self::Asin => Self::Asin,
+            Self::Acos => Self::Acos,
+            Self::Atan => Self::Atan,
+            Self::Exp => Self::Exp,
+            Self::Ln => Self::Ln,
+            Self::Log10 => Self::Log10,
+            Self::Log => Self::Log,
+            Self::Hypot => Self::Hypot,
+            Self::Pi => Self::Pi,
+            Self::E => Self::E,
+            Self::Clamp => Self::Clamp,
+            Self::Gcd => Self::Gcd,
+            Self::Lcm => Self::Lcm,
+            Self::Factorial => Self::Factorial,
+            Self::Modpow => Self::Modpow,
+            Self::Isqrt => Self::Isqrt,
+            Self::IsPrime => Self::IsPrime,
+            Self::Seed => Self::Seed,
+            Self::Randint => Self::Randint,
+            Self::Choice => Self::Choice,
+            Self::Shuffle => Self::Shuffle,
+            Self::Sample => Self::Sample,
+            Self::ReadFile => Self::ReadFile,
+            Self::ReadBytes => Self::ReadBytes,
+            Self::WriteFile => Self::WriteFile,
+            Self::AppendFile => Self::AppendFile,
+            Self::ReadLines => Self::ReadLines,
+            Self::Exists => Self::Exists,
+            Self::Remove => Self::Remove,
+            Self::Mkdir => Self::Mkdir,
+            Self::ListDir => Self::ListDir,
+            Self::Metadata => Self::Metadata,
+            Self::Open => Self::Open,
+            Self::Readline => Self::Readline,
+            Self::Write => Self::Write,
+            Self::Close => Self::Close,
+            Self::JsonParse => Self::JsonParse,
+            Self::JsonStringify => Self::JsonStringify,
+            Self::WsConnect => Self::WsConnect,
+            Self::WsSend => Self::WsSend,
+            Self::WsRecv => Self::WsRecv,
+            Self::WsClose => Self::WsClose,
+            Self::WsListen => Self::WsListen,
+            Self::CpuCount => Self::CpuCount,
+            Self::CpuUsage => Self::CpuUsage,
+            Self::Memory => Self::Memory,
+            Self::Swap => Self::Swap,
+            Self::LoadAverage => Self::LoadAverage,
+            Self::Disks => Self::Disks,
+            Self::Processes => Self::Processes,
+            Self::Pid => Self::Pid,
+            Self::Now => Self::Now,
+            Self::Monotonic => Self::Monotonic,
+            Self::Sleep => Self::Sleep,
+            Self::Date => Self::Date,
+            Self::Strftime => Self::Strftime,
+            Self::Timeit => Self::Timeit,
+            Self::Env => Self::Env,
+            Self::SetEnv => Self::SetEnv,
+            Self::EnvAll => Self::EnvAll,
+            Self::Exec => Self::Exec,
+            Self::ExecStream => Self::ExecStream,
+            Self::Exit => Self::Exit,
+            Self::ReMatch => Self::ReMatch,
+            Self::ReFindAll => Self::ReFindAll,
+            Self::ReReplace => Self::ReReplace,
+            Self::ReSplit => Self::ReSplit,
+            Self::Eprint => Self::Eprint,
+            Self::Format => Self::Format,
+            Self::Input => Self::Input,
+            Self::ReadAll => Self::ReadAll,
+            Self::ReadInt => Self::ReadInt,
+            Self::ReadFloat => Self::ReadFloat,
+            Self::Str => Self::Str,
+            Self::Float => Self::Float,
+            Self::Bool => Self::Bool,
+            Self::Bigint => Self::Bigint,
+            Self::ToRadix => Self::ToRadix,
+            Self::ToTuple => Self::ToTuple,
+            Self::Repr => Self::Repr,
+            Self::Push => Self::Push,
+            Self::Pop => Self::Pop,
+            Self::Concat => Self::Concat,
+            Self::Reverse => Self::Reverse,
+            Self::Sort => Self::Sort,
+            Self::Sorted => Self::Sorted,
+            Self::Unique => Self::Unique,
+            Self::IndexOf => Self::IndexOf,
+            Self::Sum => Self::Sum,
+            Self::Zip => Self::Zip,
+            Self::Enumerate => Self::Enumerate,
+            Self::Map => Self::Map,
+            Self::Filter => Self::Filter,
+            Self::Reduce => Self::Reduce,
+            Self::TermSize => Self::TermSize,
+            Self::Clear => Self::Clear,
+            Self::MoveCursor => Self::MoveCursor,
+            Self::Color => Self::Color,
+            Self::Bold => Self::Bold,
+            Self::Underline => Self::Underline,
+            Self::ShowCursor => Self::ShowCursor,
+            Self::HideCursor => Self::HideCursor,
+            Self::Getkey => Self::Getkey,
+            Self::Base64Encode => Self::Base64Encode,
+            Self::Base64Decode => Self::Base64Decode,
+            Self::HexEncode => Self::HexEncode,
+            Self::HexDecode => Self::HexDecode,
+            Self::UrlEncode => Self::UrlEncode,
+            Self::UrlDecode => Self::UrlDecode,
+            Self::Utf8Bytes => Self::Utf8Bytes,
+            Self::FromUtf8 => Self::FromUtf8,
+            Self::Crc32 => Self::Crc32,
+            Self::Md5 => Self::Md5,
+            Self::Sha1 => Self::Sha1,
+            Self::Sha256 => Self::Sha256,
+            Self::Hash => Self::Hash,
+            Self::CsvParse => Self::CsvParse,
+            Self::CsvStringify => Self::CsvStringify,
+            Self::CsvRows => Self::CsvRows,
+            Self::TcpConnect => Self::TcpConnect,
+            Self::TcpListen => Self::TcpListen,
+            Self::Accept => Self::Accept,
+            Self::Send => Self::Send,
+            Self::Recv => Self::Recv,
+            Self::UdpBind => Self::UdpBind,
+            Self::UdpSendTo => Self::UdpSendTo,
+            Self::UdpRecvFrom => Self::UdpRecvFrom,
+            Self::LocalAddr => Self::LocalAddr,
+            Self::HttpGet => Self::HttpGet,
+            Self::HttpRequest => Self::HttpRequest,
+            Self::HttpServe => Self::HttpServe,
+            Self::StdinLines => Self::StdinLines,
         }
     }
 }
@@ -187,59 +1665,234 @@ fn pause() -> Tuple {
 
 const READ_ARGS: [&str; 0] = [];
 
+// null once there is nothing left
 fn read() -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match read_stdin_line() {
+        Some(line) => tuple.set_string("", line),
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+const INPUT_ARGS: [&str; 1] = ["prompt"];
+
+// read() after showing the prompt on the same line
+fn input(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    print!("{}", get_string(table, "prompt"));
+    let _ = std::io::stdout().flush();
+
+    return read();
+}
+
+const READ_ALL_ARGS: [&str; 0] = [];
+
+// everything left in stdin
+fn read_all(vec_table: &mut VecTable) -> Tuple {
     let mut input = String::new();
-    std::io::stdin()
-        .read_line(&mut input)
-        .expect("error: unable to read user input");
+    let mut tuple = Tuple::new();
 
-    if input.len() > 0 {
-        let ch = input.chars().nth(input.len() - 1).unwrap();
+    match std::io::Read::read_to_string(&mut std::io::stdin(), &mut input) {
+        Ok(_) => tuple.set_string("", input),
+        Err(err) => vec_table.set_error(&format!("can't read stdin: {}", err)),
+    }
 
-        if ch == 10 as char {
-            input.pop();
+    return tuple;
+}
+
+const STDIN_LINES_ARGS: [&str; 0] = [];
+
+// the lines of stdin, read one at a time while iterating
+fn stdin_lines(vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_iterator("", vec_table.iterators.add(Iter::Stdin(None)));
+
+    return tuple;
+}
+
+const READ_INT_ARGS: [&str; 0] = [];
+
+// the next word of stdin (not the whole line), null once there is nothing left
+// the blanks after it are read with it up to the end of its line, a read() after it gets the next line
+fn read_int(vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match read_stdin_word() {
+        Some(word) => {
+            parse_value(&mut tuple, word.clone());
+
+            if tuple.get(0).kind != Kind::BigInt {
+                vec_table.set_error(&format!("{} isn't an integer", word));
+            }
         }
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+const READ_FLOAT_ARGS: [&str; 0] = [];
+
+fn read_float(vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match read_stdin_word() {
+        Some(word) => match word.parse::<f64>() {
+            Ok(value) => tuple.set_number("", value),
+            Err(_) => vec_table.set_error(&format!("{} isn't a number", word)),
+        },
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+// without its end of line, None at the end of stdin
+pub fn read_stdin_line() -> Option<String> {
+    let mut input = String::new();
+
+    match std::io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => return None,
+        Ok(_) => {}
     }
 
-    if input.len() > 0 {
-        let ch = input.chars().nth(input.len() - 1).unwrap();
+    if input.ends_with('\n') {
+        input.pop();
 
-        if ch == 13 as char {
+        if input.ends_with('\r') {
             input.pop();
         }
     }
 
-    let mut tuple = Tuple::new();
-    tuple.set_string("", input);
+    return Some(input);
+}
 
-    return tuple;
+// skips the white spaces before it and the blanks after it, a line break after it included
+fn read_stdin_word() -> Option<String> {
+    let stdin = std::io::stdin();
+    let mut lock = stdin.lock();
+    let mut word = Vec::new();
+    let mut ended = false; // only the blanks up to the end of the line are left to skip
+
+    loop {
+        let (used, done) = match lock.fill_buf() {
+            Ok([]) => (0, true),
+            Ok(buf) => {
+                let mut used = 0;
+                let mut done = false;
+
+                for byte in buf.iter() {
+                    if byte.is_ascii_whitespace() {
+                        ended = !word.is_empty();
+
+                        if ended && *byte == b'\n' {
+                            used += 1;
+                            done = true;
+                            break;
+                        }
+                    } else if ended {
+                        done = true;
+                        break;
+                    } else {
+                        word.push(*byte);
+                    }
+
+                    used += 1;
+                }
+
+                (used, done)
+            }
+            Err(_) => (0, true),
+        };
+
+        lock.consume(used);
+
+        if done {
+            break;
+        }
+    }
+
+    if word.is_empty() {
+        return None;
+    }
+
+    return Some(String::from_utf8_lossy(&word).to_string());
 }
 
 const PRINT_ARGS: [&str; 1] = ["text?"]; // meant to end with ENUMERATE_ARGS, if ENUMERATE_ARGS isn't "?" anymore or doesn't exist, then please fix this
 
+// the arguments one after the other, sep = goes between them and end = after them (a new line by default)
 fn print(vec_table: &mut VecTable) -> Tuple {
+    let text = get_printed(vec_table);
+    print!("{}", text);
+    let _ = std::io::stdout().flush();
+
+    return Tuple::new();
+}
+
+const EPRINT_ARGS: [&str; 1] = ["text?"];
+
+// like print but on stderr
+fn eprint(vec_table: &mut VecTable) -> Tuple {
+    let text = get_printed(vec_table);
+    std::eprint!("{}", text);
+
+    return Tuple::new();
+}
+
+fn get_printed(vec_table: &mut VecTable) -> String {
     let table = vec_table.get_level(vec_table.len() - 1);
 
     let text = get_tuple(table, "text");
 
-    fn prin(text: &Tuple) {
+    fn prin(string: &mut String, text: &Tuple) {
         for i in 0..(text.len()) {
             let var = text.get(i);
 
             match var.kind {
-                Kind::Tuple => prin(&text.table.get_tuple(var.pos)),
+                Kind::Tuple => prin(string, &text.table.get_tuple(var.pos)),
                 _ => match var.get_string(text.get_name(i), &text.table) {
-                    Ok(string) => print!("{}", string),
-                    Err(err) => print!("{}", err),
+                    Ok(value) => *string += &value,
+                    Err(err) => *string += &err,
                 },
             }
         }
     }
 
-    prin(&text);
-    println!("");
+    let mut sep = String::new();
+    let mut end = String::from("\n");
+    let mut values = Tuple::new();
 
-    return Tuple::new();
+    for i in 0..(text.len()) {
+        let var = text.get(i);
+        let name = text.get_name(i);
+
+        match get_keyword(name) {
+            Some("sep") => sep = var.get_string(name, &text.table).unwrap(),
+            Some("end") => end = var.get_string(name, &text.table).unwrap(),
+            _ => values.push(var, name, &text.table),
+        }
+    }
+
+    let mut string = String::new();
+
+    for i in 0..(values.len()) {
+        if i > 0 {
+            string += &sep;
+        }
+
+        let mut value = Tuple::new();
+        value.push(values.get(i), values.get_name(i), &values.table);
+        prin(&mut string, &value);
+    }
+
+    string += &end;
+
+    return string;
 }
 
 const INT_ARGS: [&str; 1] = ["num"];
@@ -358,7 +2011,7 @@ fn rand(vec_table: &mut VecTable) -> Tuple {
 
     let delta = max - min;
 
-    tuple.set_number("", (random::<f64>() * delta) + min);
+    tuple.set_number("", (vec_table.rng.gen::<f64>() * delta) + min);
 
     return tuple;
 }
@@ -369,9 +2022,7 @@ fn kind(vec_table: &mut VecTable) -> Tuple {
     let table = vec_table.get_level(vec_table.len() - 1);
     let mut tuple = Tuple::new();
 
-    let var = get_tuple(table, "var");
-
-    tuple.set_string("", var.get(0).kind.to_string());
+    tuple.set_string("", table.get("var").kind.to_string());
 
     return tuple;
 }
@@ -383,7 +2034,13 @@ fn parse(vec_table: &mut VecTable) -> Tuple {
     let mut tuple = Tuple::new();
 
     let var = table.get("str").get_string("str", table).unwrap();
+    parse_value(&mut tuple, var);
+
+    return tuple;
+}
 
+// null, a bool, an integer, a number or else the string itself
+fn parse_value(tuple: &mut Tuple, var: String) {
     if var == "null" {
         tuple.set_null("");
     } else {
@@ -398,30 +2055,5078 @@ fn parse(vec_table: &mut VecTable) -> Tuple {
             },
         };
     }
+}
+
+const ITER_ARGS: [&str; 1] = ["value"];
+
+fn iter(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    let iterator = match var.kind {
+        Kind::Iterator => {
+            tuple.set_iterator("", table.get_iterator(var.pos));
+            return tuple;
+        }
+        Kind::Tuple => Iter::Tuple(table.get_tuple(var.pos), 0),
+        Kind::String => Iter::String(table.get_string(var.pos).chars().collect(), 0),
+        Kind::Range => Iter::Range(table.get_range(var.pos), BigInt::zero()),
+        Kind::Null => Iter::Done,
+        _ => Iter::Tuple(get_tuple(table, "value"), 0),
+    };
+
+    tuple.set_iterator("", vec_table.iterators.add(iterator));
 
     return tuple;
 }
 
-#[allow(dead_code)]
-fn get_number(table: &mut Table, entry: &str) -> f64 {
-    match table.get(entry).get_number(entry, table) {
-        Ok(num) => num,
-        Err(_) => 0.0,
+const NEXT_ARGS: [&str; 1] = ["iterator"];
+
+fn next(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    match get_iterator(table, "iterator") {
+        Some(pos) => match vec_table.next_iterator(pos) {
+            Some(value) => value,
+            None => Tuple::new(),
+        },
+        None => Tuple::new(),
     }
 }
 
-#[allow(dead_code)]
-fn get_bigint(table: &mut Table, entry: &str) -> BigInt {
-    match table.get(entry).get_bigint(entry, table) {
-        Ok(bigint) => bigint,
-        Err(_) => BigInt::zero(),
+const HAS_NEXT_ARGS: [&str; 1] = ["iterator"];
+
+fn has_next(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    let more = match get_iterator(table, "iterator") {
+        Some(pos) => vec_table.has_next_iterator(pos),
+        None => false,
+    };
+
+    tuple.set_bool("", more);
+
+    return tuple;
+}
+
+const LEN_ARGS: [&str; 1] = ["value"];
+
+fn len(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    match var.kind {
+        Kind::String => {
+            tuple.set_bigint("", BigInt::from(table.get_string(var.pos).chars().count()))
+        }
+        Kind::Tuple => tuple.set_bigint("", BigInt::from(table.get_tuple(var.pos).len())),
+        Kind::Range => tuple.set_bigint("", table.get_range(var.pos).len()),
+        Kind::Null => tuple.set_bigint("", BigInt::zero()),
+        _ => tuple.set_bigint("", BigInt::one()),
     }
+
+    return tuple;
 }
 
-#[allow(dead_code)]
-fn get_tuple(table: &mut Table, entry: &str) -> Tuple {
-    match table.get(entry).get_tuple(entry, table) {
-        Ok(tuple) => tuple,
-        Err(_) => Tuple::new(),
+const STEP_ARGS: [&str; 2] = ["range", "step"];
+
+fn step(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    if let Ok(mut range) = table.get("range").get_range("range", table) {
+        range.step = get_bigint(table, "step");
+        tuple.set_range("", range);
+    }
+
+    return tuple;
+}
+
+const SLICE_ARGS: [&str; 2] = ["value", "range"];
+
+fn slice(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+
+    let range = match table.get("range").get_range("range", table) {
+        Ok(range) => range,
+        Err(_) => return Tuple::new(),
+    };
+
+    return get_slice(table, &var, &range);
+}
+
+const AT_ARGS: [&str; 2] = ["value", "index"];
+
+// a[i] is a.at(i), giving null if there is nothing at that index
+fn at(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let index = table.get("index").clone();
+    let mut tuple = Tuple::new();
+
+    if index.kind == Kind::Range {
+        let range = table.get_range(index.pos);
+        return get_slice(table, &var, &range);
+    }
+
+    match var.kind {
+        Kind::Tuple => {
+            let value = table.get_tuple(var.pos);
+
+            if index.kind == Kind::String {
+                let key = table.get_string(index.pos);
+
+                for i in 0..(value.len()) {
+                    if get_real_name(value.get_name(i)) == key {
+                        tuple.push(value.get(i), value.get_name(i), &value.table);
+                        break;
+                    }
+                }
+            } else if let Some(i) = get_index(table, "index", value.len()) {
+                tuple.push(value.get(i), value.get_name(i), &value.table);
+            }
+        }
+        Kind::String => {
+            let chars: Vec<char> = table.get_string(var.pos).chars().collect();
+
+            if let Some(i) = get_index(table, "index", chars.len()) {
+                tuple.set_string("", chars[i].to_string());
+            }
+        }
+        Kind::Range => {
+            let range = table.get_range(var.pos);
+            let mut i = get_bigint(table, "index");
+
+            if i.is_negative() {
+                i += range.len();
+            }
+
+            if !i.is_negative() && i < range.len() {
+                tuple.set_bigint("", range.get(&i));
+            }
+        }
+        _ => {}
+    }
+
+    if tuple.len() == 0 {
+        tuple.set_null("");
+    }
+
+    return tuple;
+}
+
+fn get_slice(table: &Table, var: &Variable, range: &Range) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match var.kind {
+        Kind::String => {
+            let chars: Vec<char> = table.get_string(var.pos).chars().collect();
+            let mut string = String::new();
+
+            for i in get_indexes(range, chars.len()) {
+                string.push(chars[i]);
+            }
+
+            tuple.set_string("", string);
+        }
+        Kind::Tuple => {
+            let value = table.get_tuple(var.pos);
+            let mut tuple_b = Tuple::new();
+
+            for i in get_indexes(range, value.len()) {
+                tuple_b.push(value.get(i), value.get_name(i), &value.table);
+            }
+
+            tuple.set_tuple("", tuple_b);
+        }
+        _ => {}
+    }
+
+    return tuple;
+}
+
+// position inside of a sequence of that length, negative ones counting from the end
+fn get_index(table: &mut Table, entry: &str, len: usize) -> Option<usize> {
+    let mut index = get_bigint(table, entry);
+
+    if index.is_negative() {
+        index += BigInt::from(len);
     }
+
+    match index.to_usize() {
+        Some(i) if i < len => Some(i),
+        _ => None,
+    }
+}
+
+// positions of the range that are inside of a sequence of that length, without going through the ones before or after it
+fn get_indexes(range: &Range, len: usize) -> Vec<usize> {
+    let len = BigInt::from(len);
+    let mut indexes = Vec::new();
+
+    if range.step.is_zero() {
+        return indexes;
+    }
+
+    let mut k = {
+        if range.step.is_positive() && range.start.is_negative() {
+            (-&range.start).div_ceil(&range.step)
+        } else if range.step.is_negative() && range.start >= len {
+            (&range.start - &len + BigInt::one()).div_ceil(&-&range.step)
+        } else {
+            BigInt::zero()
+        }
+    };
+
+    let count = range.len();
+
+    while k < count {
+        let i = range.get(&k);
+
+        if i.is_negative() || i >= len {
+            break;
+        }
+
+        indexes.push(i.to_usize().unwrap());
+        k += BigInt::one();
+    }
+
+    return indexes;
+}
+
+const ASSERT_ARGS: [&str; 2] = ["condition", "message"];
+
+// stops the script with where it failed if the condition is false
+fn assert(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let condition = table
+        .get("condition")
+        .get_bool("condition", table)
+        .unwrap_or(false);
+
+    if !condition {
+        let message = {
+            let var = table.get("message");
+
+            match var.kind {
+                Kind::Null => String::new(),
+                _ => format!(": {}", var.get_string("message", table).unwrap()),
+            }
+        };
+
+        vec_table.set_error(&format!("assertion failed{}", message));
+    }
+
+    return Tuple::new();
+}
+
+const SPLIT_ARGS: [&str; 2] = ["str", "separator"];
+
+// without a separator it splits on whitespaces
+fn split(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let mut parts = Tuple::new();
+
+    if table.get("separator").kind == Kind::Null {
+        for part in string.split_whitespace() {
+            parts.set_string("", part.to_string());
+        }
+    } else {
+        let separator = get_string(table, "separator");
+
+        if separator.len() == 0 {
+            for ch in string.chars() {
+                parts.set_string("", ch.to_string());
+            }
+        } else {
+            for part in string.split(separator.as_str()) {
+                parts.set_string("", part.to_string());
+            }
+        }
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", parts);
+
+    return tuple;
+}
+
+const JOIN_ARGS: [&str; 2] = ["values", "separator"];
+
+fn join(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let values = get_tuple(table, "values");
+    let separator = get_string(table, "separator");
+    let mut string = String::new();
+
+    for i in 0..(values.len()) {
+        if i > 0 {
+            string.push_str(&separator);
+        }
+
+        if let Ok(value) = values.get(i).get_string(values.get_name(i), &values.table) {
+            string.push_str(&value);
+        }
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+const REPLACE_ARGS: [&str; 3] = ["str", "from", "to"];
+
+fn replace(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let from = get_string(table, "from");
+    let to = get_string(table, "to");
+    let mut tuple = Tuple::new();
+
+    if from.len() == 0 {
+        tuple.set_string("", string);
+    } else {
+        tuple.set_string("", string.replace(&from, &to));
+    }
+
+    return tuple;
+}
+
+const FIND_ARGS: [&str; 2] = ["str", "pattern"];
+
+// position in chars of the first occurence, null if there is none
+fn find(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let pattern = get_string(table, "pattern");
+    let mut tuple = Tuple::new();
+
+    match string.find(&pattern) {
+        Some(n) => tuple.set_bigint("", BigInt::from(string[..n].chars().count())),
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+const STARTS_WITH_ARGS: [&str; 2] = ["str", "pattern"];
+
+fn starts_with(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let pattern = get_string(table, "pattern");
+
+    let mut tuple = Tuple::new();
+    tuple.set_bool("", string.starts_with(&pattern));
+
+    return tuple;
+}
+
+const ENDS_WITH_ARGS: [&str; 2] = ["str", "pattern"];
+
+fn ends_with(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let pattern = get_string(table, "pattern");
+
+    let mut tuple = Tuple::new();
+    tuple.set_bool("", string.ends_with(&pattern));
+
+    return tuple;
+}
+
+const UPPER_ARGS: [&str; 1] = ["str"];
+
+fn upper(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", get_string(table, "str").to_uppercase());
+
+    return tuple;
+}
+
+const LOWER_ARGS: [&str; 1] = ["str"];
+
+fn lower(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", get_string(table, "str").to_lowercase());
+
+    return tuple;
+}
+
+const TRIM_ARGS: [&str; 1] = ["str"];
+
+fn trim(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", get_string(table, "str").trim().to_string());
+
+    return tuple;
+}
+
+const SUBSTR_ARGS: [&str; 3] = ["str", "start", "length"];
+
+// length chars from start, negative start counting from the end and no length going to the end
+fn substr(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let chars: Vec<char> = get_string(table, "str").chars().collect();
+
+    let mut start = get_bigint(table, "start");
+
+    if start.is_negative() {
+        start += BigInt::from(chars.len());
+    }
+
+    let start = start.to_usize().unwrap_or(0).min(chars.len());
+
+    let end = {
+        if table.get("length").kind == Kind::Null {
+            chars.len()
+        } else {
+            match get_bigint(table, "length").to_usize() {
+                Some(length) => start.saturating_add(length).min(chars.len()),
+                None => start,
+            }
+        }
+    };
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", chars[start..end].iter().collect());
+
+    return tuple;
+}
+
+const CHARS_ARGS: [&str; 1] = ["str"];
+
+fn chars(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut chars = Tuple::new();
+
+    for ch in get_string(table, "str").chars() {
+        chars.set_string("", ch.to_string());
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", chars);
+
+    return tuple;
+}
+
+const REPEAT_ARGS: [&str; 2] = ["str", "count"];
+
+fn repeat(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+    let count = get_bigint(table, "count").to_usize().unwrap_or(0);
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", string.repeat(count));
+
+    return tuple;
+}
+
+const CONTAINS_ARGS: [&str; 2] = ["str", "pattern"];
+
+// an element equal to it for a tuple, a part of it for a string
+fn contains(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    if table.get("str").kind == Kind::Tuple {
+        let found = get_index_of(&get_tuple(table, "str"), &get_element(table, "pattern"));
+
+        let mut tuple = Tuple::new();
+        tuple.set_bool("", found.is_some());
+
+        return tuple;
+    }
+
+    let string = get_string(table, "str");
+    let pattern = get_string(table, "pattern");
+
+    let mut tuple = Tuple::new();
+    tuple.set_bool("", string.contains(&pattern));
+
+    return tuple;
+}
+
+const ABS_ARGS: [&str; 1] = ["num"];
+
+fn abs(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    if table.get("num").kind == Kind::Number {
+        tuple.set_number("", get_number(table, "num").abs());
+    } else {
+        tuple.set_bigint("", get_bigint(table, "num").abs());
+    }
+
+    return tuple;
+}
+
+const MIN_ARGS: [&str; 1] = ["values?"];
+
+// min(a, b, ...) or min(tuple)
+fn min(vec_table: &mut VecTable) -> Tuple {
+    return min_max(vec_table, true);
+}
+
+const MAX_ARGS: [&str; 1] = ["values?"];
+
+fn max(vec_table: &mut VecTable) -> Tuple {
+    return min_max(vec_table, false);
+}
+
+fn min_max(vec_table: &mut VecTable, min: bool) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut values = get_tuple(table, "values");
+    let mut tuple = Tuple::new();
+
+    if values.len() == 1 && values.get(0).kind == Kind::Tuple {
+        values = values.table.get_tuple(values.get(0).pos);
+    }
+
+    let mut float = false;
+
+    for i in 0..(values.len()) {
+        match values.get(i).kind {
+            Kind::Number => float = true,
+            Kind::BigInt => {}
+            _ => return tuple,
+        }
+    }
+
+    if values.len() == 0 {
+        return tuple;
+    }
+
+    // every value is compared as a number as soon as one of them isn't an integer
+    if float {
+        let mut best = values
+            .get(0)
+            .get_number(values.get_name(0), &values.table)
+            .unwrap();
+
+        for i in 1..(values.len()) {
+            let num = values
+                .get(i)
+                .get_number(values.get_name(i), &values.table)
+                .unwrap();
+
+            if (min && num < best) || (!min && num > best) {
+                best = num;
+            }
+        }
+
+        tuple.set_number("", best);
+    } else {
+        let mut best = values
+            .get(0)
+            .get_bigint(values.get_name(0), &values.table)
+            .unwrap();
+
+        for i in 1..(values.len()) {
+            let num = values
+                .get(i)
+                .get_bigint(values.get_name(i), &values.table)
+                .unwrap();
+
+            if (min && num < best) || (!min && num > best) {
+                best = num;
+            }
+        }
+
+        tuple.set_bigint("", best);
+    }
+
+    return tuple;
+}
+
+const SIN_ARGS: [&str; 1] = ["num"];
+
+fn sin(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::sin);
+}
+
+const COS_ARGS: [&str; 1] = ["num"];
+
+fn cos(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::cos);
+}
+
+const TAN_ARGS: [&str; 1] = ["num"];
+
+fn tan(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::tan);
+}
+
+const ASIN_ARGS: [&str; 1] = ["num"];
+
+fn asin(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::asin);
+}
+
+const ACOS_ARGS: [&str; 1] = ["num"];
+
+fn acos(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::acos);
+}
+
+const ATAN_ARGS: [&str; 1] = ["num"];
+
+fn atan(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::atan);
+}
+
+const EXP_ARGS: [&str; 1] = ["num"];
+
+fn exp(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::exp);
+}
+
+const LN_ARGS: [&str; 1] = ["num"];
+
+fn ln(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::ln);
+}
+
+const LOG10_ARGS: [&str; 1] = ["num"];
+
+fn log10(vec_table: &mut VecTable) -> Tuple {
+    return float_fn(vec_table, f64::log10);
+}
+
+// applies a function of floats to num, integers being converted to it first
+fn float_fn(vec_table: &mut VecTable, function: fn(f64) -> f64) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_number("", function(get_number(table, "num")));
+
+    return tuple;
+}
+
+const LOG_ARGS: [&str; 2] = ["num", "base"];
+
+// without a base it is the natural logarithm
+fn log(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let num = get_number(table, "num");
+    let mut tuple = Tuple::new();
+
+    if table.get("base").kind == Kind::Null {
+        tuple.set_number("", num.ln());
+    } else {
+        tuple.set_number("", num.log(get_number(table, "base")));
+    }
+
+    return tuple;
+}
+
+const HYPOT_ARGS: [&str; 2] = ["a", "b"];
+
+fn hypot(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_number("", get_number(table, "a").hypot(get_number(table, "b")));
+
+    return tuple;
+}
+
+const PI_ARGS: [&str; 0] = [];
+
+fn pi(_vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_number("", std::f64::consts::PI);
+
+    return tuple;
+}
+
+const E_ARGS: [&str; 0] = [];
+
+fn e(_vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_number("", std::f64::consts::E);
+
+    return tuple;
+}
+
+const CLAMP_ARGS: [&str; 3] = ["num", "min", "max"];
+
+fn clamp(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    if table.get("num").kind == Kind::Number
+        || table.get("min").kind == Kind::Number
+        || table.get("max").kind == Kind::Number
+    {
+        let num = get_number(table, "num");
+        let min = get_number(table, "min");
+        let max = get_number(table, "max");
+
+        tuple.set_number("", num.max(min).min(max));
+    } else {
+        let num = get_bigint(table, "num");
+        let min = get_bigint(table, "min");
+        let max = get_bigint(table, "max");
+
+        tuple.set_bigint("", num.max(min).min(max));
+    }
+
+    return tuple;
+}
+
+const GCD_ARGS: [&str; 2] = ["a", "b"];
+
+fn gcd(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_bigint("", get_bigint(table, "a").gcd(&get_bigint(table, "b")));
+
+    return tuple;
+}
+
+const LCM_ARGS: [&str; 2] = ["a", "b"];
+
+fn lcm(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_bigint("", get_bigint(table, "a").lcm(&get_bigint(table, "b")));
+
+    return tuple;
+}
+
+const FACTORIAL_ARGS: [&str; 1] = ["num"];
+
+fn factorial(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let num = get_bigint(table, "num");
+    let mut tuple = Tuple::new();
+
+    if num.is_negative() {
+        tuple.set_null("");
+        return tuple;
+    }
+
+    let mut result = BigInt::one();
+    let mut i = BigInt::from(2);
+
+    while i <= num {
+        result *= &i;
+        i += 1;
+    }
+
+    tuple.set_bigint("", result);
+
+    return tuple;
+}
+
+const MODPOW_ARGS: [&str; 3] = ["num", "exp", "modulus"];
+
+// null if the exponent is negative or the modulus is 0
+fn modpow(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let num = get_bigint(table, "num");
+    let exp = get_bigint(table, "exp");
+    let modulus = get_bigint(table, "modulus");
+    let mut tuple = Tuple::new();
+
+    if exp.is_negative() || modulus.is_zero() {
+        tuple.set_null("");
+    } else {
+        tuple.set_bigint("", num.modpow(&exp, &modulus).mod_floor(&modulus));
+    }
+
+    return tuple;
+}
+
+const ISQRT_ARGS: [&str; 1] = ["num"];
+
+// the integer part of the square root, null for negative numbers
+fn isqrt(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let num = get_bigint(table, "num");
+    let mut tuple = Tuple::new();
+
+    if num.is_negative() {
+        tuple.set_null("");
+    } else {
+        tuple.set_bigint("", num.sqrt());
+    }
+
+    return tuple;
+}
+
+const IS_PRIME_ARGS: [&str; 1] = ["num"];
+
+// Miller-Rabin with the first 13 primes as bases, exact below 3.3e24 and very likely above
+fn is_prime(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let num = get_bigint(table, "num");
+
+    let mut tuple = Tuple::new();
+    tuple.set_bool("", bigint_is_prime(&num));
+
+    return tuple;
+}
+
+fn bigint_is_prime(num: &BigInt) -> bool {
+    const BASES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+    if *num < BigInt::from(2) {
+        return false;
+    }
+
+    for base in BASES.iter() {
+        let base = BigInt::from(*base);
+
+        if *num == base {
+            return true;
+        }
+
+        if num.is_multiple_of(&base) {
+            return false;
+        }
+    }
+
+    let num_minus = num - BigInt::one();
+    let mut d = num_minus.clone();
+    let mut r = 0;
+
+    while d.is_even() {
+        d >>= 1;
+        r += 1;
+    }
+
+    for base in BASES.iter() {
+        let mut x = BigInt::from(*base).modpow(&d, num);
+
+        if x.is_one() || x == num_minus {
+            continue;
+        }
+
+        let mut composite = true;
+
+        for _ in 1..r {
+            x = x.modpow(&BigInt::from(2), num);
+
+            if x == num_minus {
+                composite = false;
+                break;
+            }
+        }
+
+        if composite {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+const SEED_ARGS: [&str; 1] = ["seed"];
+
+// without a seed it goes back to an unpredictable generator
+fn seed(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    if table.get("seed").kind == Kind::Null {
+        vec_table.rng = StdRng::from_entropy();
+    } else {
+        let (_, digits) = get_bigint(table, "seed").to_u64_digits();
+        vec_table.rng = StdRng::seed_from_u64(digits.first().copied().unwrap_or(0));
+    }
+
+    return Tuple::new();
+}
+
+const RANDINT_ARGS: [&str; 2] = ["min", "max"];
+
+// integer between min and max, both included
+fn randint(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let min = get_bigint(table, "min");
+    let max = get_bigint(table, "max");
+    let mut tuple = Tuple::new();
+
+    if min > max {
+        vec_table.set_error(&format!(
+            "randint: min ({}) is bigger than max ({})",
+            min, max
+        ));
+        return tuple;
+    }
+
+    let count = max - &min + BigInt::one();
+    tuple.set_bigint("", min + random_below(&mut vec_table.rng, &count));
+
+    return tuple;
+}
+
+// uniform between 0 and limit excluded, drawing as many bits as the limit has until it falls under it
+fn random_below(rng: &mut StdRng, limit: &BigInt) -> BigInt {
+    let bits = limit.bits();
+    let extra = bits % 8; // bits used in the last byte
+    let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+
+    loop {
+        rng.fill_bytes(&mut bytes);
+
+        if extra > 0 {
+            let last = bytes.len() - 1;
+            bytes[last] &= (1u8 << extra) - 1;
+        }
+
+        let value = BigInt::from_bytes_le(Sign::Plus, &bytes);
+
+        if value < *limit {
+            return value;
+        }
+    }
+}
+
+const CHOICE_ARGS: [&str; 1] = ["values"];
+
+fn choice(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let values = get_tuple(table, "values");
+    let mut tuple = Tuple::new();
+
+    if values.len() == 0 {
+        tuple.set_null("");
+    } else {
+        let i = vec_table.rng.gen_range(0..values.len());
+        tuple.push(values.get(i), values.get_name(i), &values.table);
+    }
+
+    return tuple;
+}
+
+const SHUFFLE_ARGS: [&str; 1] = ["values"];
+
+// gives a shuffled copy of the tuple
+fn shuffle(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let values = get_tuple(table, "values");
+
+    let mut indexes: Vec<usize> = (0..values.len()).collect();
+    indexes.shuffle(&mut vec_table.rng);
+
+    return get_picked(&values, &indexes);
+}
+
+const SAMPLE_ARGS: [&str; 2] = ["values", "count"];
+
+// count different elements of the tuple in a random order
+fn sample(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let values = get_tuple(table, "values");
+
+    let count = match get_bigint(table, "count").to_usize() {
+        Some(count) if count <= values.len() => count,
+        _ => {
+            vec_table.set_error(&format!(
+                "sample: the count goes from 0 to the number of elements ({})",
+                values.len()
+            ));
+            return Tuple::new();
+        }
+    };
+
+    let indexes = rand::seq::index::sample(&mut vec_table.rng, values.len(), count).into_vec();
+
+    return get_picked(&values, &indexes);
+}
+
+fn get_picked(values: &Tuple, indexes: &[usize]) -> Tuple {
+    let mut picked = Tuple::new();
+
+    for i in indexes.iter() {
+        picked.push(values.get(*i), values.get_name(*i), &values.table);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", picked);
+
+    return tuple;
+}
+
+const READ_FILE_ARGS: [&str; 1] = ["path"];
+
+fn read_file(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => tuple.set_string("", content),
+        Err(err) => vec_table.set_error(&format!("can't read {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const READ_BYTES_ARGS: [&str; 1] = ["path"];
+
+// the same tuple of bytes as utf8_bytes() so a file that isn't text can still be hashed
+fn read_bytes(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    match std::fs::read(&path) {
+        Ok(content) => tuple.set_tuple("", get_byte_tuple(&content)),
+        Err(err) => vec_table.set_error(&format!("can't read {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const WRITE_FILE_ARGS: [&str; 2] = ["path", "text"];
+
+fn write_file(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let text = get_string(table, "text");
+
+    if let Err(err) = std::fs::write(&path, text) {
+        vec_table.set_error(&format!("can't write {}: {}", path, err));
+    }
+
+    return Tuple::new();
+}
+
+const APPEND_FILE_ARGS: [&str; 2] = ["path", "text"];
+
+fn append_file(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let text = get_string(table, "text");
+
+    let result = std::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(text.as_bytes()));
+
+    if let Err(err) = result {
+        vec_table.set_error(&format!("can't write {}: {}", path, err));
+    }
+
+    return Tuple::new();
+}
+
+const READ_LINES_ARGS: [&str; 1] = ["path"];
+
+// the lines of a file, stdin_lines() for the ones of stdin
+fn read_lines(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    match std::fs::read_to_string(&path) {
+        Ok(content) => {
+            let mut lines = Tuple::new();
+
+            for line in content.lines() {
+                lines.set_string("", line.to_string());
+            }
+
+            tuple.set_tuple("", lines);
+        }
+        Err(err) => vec_table.set_error(&format!("can't read {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const EXISTS_ARGS: [&str; 1] = ["path"];
+
+fn exists(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+
+    let mut tuple = Tuple::new();
+    tuple.set_bool("", std::path::Path::new(&path).exists());
+
+    return tuple;
+}
+
+const REMOVE_ARGS: [&str; 1] = ["path"];
+
+// removes a file or an empty directory
+fn remove(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+
+    let result = {
+        if std::path::Path::new(&path).is_dir() {
+            std::fs::remove_dir(&path)
+        } else {
+            std::fs::remove_file(&path)
+        }
+    };
+
+    if let Err(err) = result {
+        vec_table.set_error(&format!("can't remove {}: {}", path, err));
+    }
+
+    return Tuple::new();
+}
+
+const MKDIR_ARGS: [&str; 1] = ["path"];
+
+// also makes the missing parents
+fn mkdir(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+
+    if let Err(err) = std::fs::create_dir_all(&path) {
+        vec_table.set_error(&format!("can't make {}: {}", path, err));
+    }
+
+    return Tuple::new();
+}
+
+const LIST_DIR_ARGS: [&str; 1] = ["path"];
+
+// names of what is in the directory in alphabetical order
+fn list_dir(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    match std::fs::read_dir(&path) {
+        Ok(entries) => {
+            let mut names: Vec<String> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect();
+
+            names.sort();
+
+            let mut list = Tuple::new();
+
+            for name in names.into_iter() {
+                list.set_string("", name);
+            }
+
+            tuple.set_tuple("", list);
+        }
+        Err(err) => vec_table.set_error(&format!("can't list {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const METADATA_ARGS: [&str; 1] = ["path"];
+
+// (size, modified, is_dir) with modified in seconds since 1970
+fn metadata(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    match std::fs::metadata(&path) {
+        Ok(metadata) => {
+            let modified = match metadata.modified() {
+                Ok(time) => match time.duration_since(std::time::UNIX_EPOCH) {
+                    Ok(duration) => duration.as_secs_f64(),
+                    Err(_) => 0.0,
+                },
+                Err(_) => 0.0,
+            };
+
+            let mut info = Tuple::new();
+            info.set_bigint("size", BigInt::from(metadata.len()));
+            info.set_number("modified", modified);
+            info.set_bool("is_dir", metadata.is_dir());
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) => vec_table.set_error(&format!("can't read {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const OPEN_ARGS: [&str; 2] = ["path", "mode"];
+
+// mode is "r" to read line by line (the default), "w" to write over it or "a" to write after it
+fn open(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+
+    let mode = match table.get("mode").kind {
+        Kind::Null => String::from("r"),
+        _ => get_string(table, "mode"),
+    };
+
+    let mut tuple = Tuple::new();
+
+    let handle = match mode.as_str() {
+        "r" => std::fs::File::open(&path).map(|file| Handle::Reader(BufReader::new(file))),
+        "w" => std::fs::File::create(&path).map(Handle::Writer),
+        "a" => std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .map(Handle::Writer),
+        _ => {
+            vec_table.set_error(&format!("unknown mode {}", mode));
+            return tuple;
+        }
+    };
+
+    match handle {
+        Ok(handle) => tuple.set_handle("", vec_table.handles.add(handle)),
+        Err(err) => vec_table.set_error(&format!("can't open {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+const READLINE_ARGS: [&str; 1] = ["handle"];
+
+// the next line without its end, null once there is nothing left
+fn readline(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "handle");
+    let mut tuple = Tuple::new();
+
+    let reader = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::Reader(reader)) => reader,
+        _ => {
+            vec_table.set_error("the handle isn't open for reading");
+            return tuple;
+        }
+    };
+
+    let mut line = String::new();
+
+    match reader.read_line(&mut line) {
+        Ok(0) => tuple.set_null(""),
+        Ok(_) => {
+            if line.ends_with('\n') {
+                line.pop();
+
+                if line.ends_with('\r') {
+                    line.pop();
+                }
+            }
+
+            tuple.set_string("", line);
+        }
+        Err(err) => vec_table.set_error(&format!("can't read: {}", err)),
+    }
+
+    return tuple;
+}
+
+const WRITE_ARGS: [&str; 2] = ["handle", "text"];
+
+fn write(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "handle");
+    let text = get_string(table, "text");
+
+    let result = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::Writer(file)) => file.write_all(text.as_bytes()),
+        _ => {
+            vec_table.set_error("the handle isn't open for writing");
+            return Tuple::new();
+        }
+    };
+
+    if let Err(err) = result {
+        vec_table.set_error(&format!("can't write: {}", err));
+    }
+
+    return Tuple::new();
+}
+
+const CLOSE_ARGS: [&str; 1] = ["handle"];
+
+// the handle stays closed, using it again is an error
+fn close(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    if let Some(handle) = get_handle(table, "handle").and_then(|pos| vec_table.handles.get_mut(pos))
+    {
+        *handle = Handle::Closed;
+    }
+
+    return Tuple::new();
+}
+
+const JSON_PARSE_ARGS: [&str; 1] = ["text"];
+
+fn json_parse(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+
+    match parse_json(&text) {
+        Ok(tuple) => return tuple,
+        Err(err) => vec_table.set_error(&format!("invalid json: {}", err)),
+    }
+
+    return Tuple::new();
+}
+
+const JSON_STRINGIFY_ARGS: [&str; 2] = ["value", "indent"];
+
+// a tuple with any named element is an object, otherwise it is an array
+fn json_stringify(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    let indent = if table.get("indent").kind == Kind::Null {
+        None
+    } else {
+        Some(get_bigint(table, "indent").to_usize().unwrap_or(0))
+    };
+
+    tuple.set_string("", get_json(table.get("value"), table, indent, 1));
+
+    return tuple;
+}
+
+// written by hand rather than through JsonValue so a bigint keeps all of its digits
+fn get_json(var: &Variable, table: &Table, indent: Option<usize>, level: usize) -> String {
+    match var.kind {
+        Kind::String => JsonValue::from(table.get_string(var.pos)).dump(),
+        Kind::Bool => JsonValue::from(table.get_bool(var.pos)).dump(),
+        Kind::Number => {
+            let number = table.get_number(var.pos);
+
+            if number.is_finite() {
+                JsonValue::from(number).dump()
+            } else {
+                String::from("null")
+            }
+        }
+        Kind::BigInt => table.get_bigint(var.pos).to_string(),
+        Kind::Tuple => {
+            let tuple = table.get_tuple(var.pos);
+            let mut object = false;
+
+            for i in 0..(tuple.len()) {
+                if get_real_name(tuple.get_name(i)).len() > 0 {
+                    object = true;
+                    break;
+                }
+            }
+
+            // the same key twice keeps the place of the first and the value of the last
+            let mut items: Vec<(String, usize)> = Vec::new();
+
+            for i in 0..(tuple.len()) {
+                let key = match get_real_name(tuple.get_name(i)) {
+                    "" => i.to_string(),
+                    key => key.to_string(),
+                };
+
+                match items.iter().position(|(other, _)| object && *other == key) {
+                    Some(pos) => items[pos].1 = i,
+                    None => items.push((key, i)),
+                }
+            }
+
+            let (open, close) = if object { ("{", "}") } else { ("[", "]") };
+
+            if items.is_empty() {
+                return format!("{}{}", open, close);
+            }
+
+            let (separator, end) = match indent {
+                Some(indent) => (
+                    format!("\n{}", " ".repeat(indent * level)),
+                    format!("\n{}", " ".repeat(indent * (level - 1))),
+                ),
+                None => (String::new(), String::new()),
+            };
+
+            let mut json = String::from(open);
+
+            for i in 0..(items.len()) {
+                if i > 0 {
+                    json.push(',');
+                }
+
+                json.push_str(&separator);
+
+                if object {
+                    json.push_str(&JsonValue::from(items[i].0.as_str()).dump());
+                    json.push_str(if indent.is_some() { ": " } else { ":" });
+                }
+
+                let value = get_json(tuple.get(items[i].1), &tuple.table, indent, level + 1);
+                json.push_str(&value);
+            }
+
+            json.push_str(&end);
+            json.push_str(close);
+
+            json
+        }
+        Kind::Range => JsonValue::from(table.get_range(var.pos).to_string()).dump(),
+        Kind::Null | Kind::Operator | Kind::Function | Kind::Iterator | Kind::Handle => {
+            String::from("null")
+        }
+    }
+}
+
+const WS_CONNECT_ARGS: [&str; 1] = ["url"];
+
+fn ws_connect(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let url = get_string(table, "url");
+    let mut tuple = Tuple::new();
+
+    let client = match ClientBuilder::new(&url) {
+        Ok(mut builder) => builder.connect_insecure().map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+
+    match client {
+        Ok(client) => tuple.set_handle("", vec_table.handles.add(Handle::WebSocket(client))),
+        Err(err) => vec_table.set_error(&format!("can't connect to {}: {}", url, err)),
+    }
+
+    return tuple;
+}
+
+const WS_SEND_ARGS: [&str; 2] = ["conn", "text"];
+
+fn ws_send(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "conn");
+    let text = get_string(table, "text");
+
+    let result = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::WebSocket(client)) => client.send_message(&OwnedMessage::Text(text)),
+        _ => {
+            vec_table.set_error("the handle isn't an open websocket");
+            return Tuple::new();
+        }
+    };
+
+    if let Err(err) = result {
+        vec_table.set_error(&format!("can't send: {}", err));
+    }
+
+    return Tuple::new();
+}
+
+const WS_RECV_ARGS: [&str; 2] = ["conn", "timeout"];
+
+// the next message as a string, null if nothing came in time (in seconds) or the other side closed
+fn ws_recv(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "conn");
+    let mut tuple = Tuple::new();
+
+    let timeout = match table.get("timeout").kind {
+        Kind::Null => None,
+        _ => Some(std::time::Duration::from_secs_f64(
+            get_number(table, "timeout").max(0.001),
+        )),
+    };
+
+    let received = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::WebSocket(client)) => match client.stream_ref().set_read_timeout(timeout) {
+            Ok(()) => ws_receive(client),
+            Err(err) => Err(err.to_string()),
+        },
+        _ => {
+            vec_table.set_error("the handle isn't an open websocket");
+            return tuple;
+        }
+    };
+
+    match received {
+        Ok((Some(text), _)) => tuple.set_string("", text),
+        Ok((None, closed)) => {
+            if closed {
+                vec_table.handles[handle.unwrap()] = Handle::Closed;
+            }
+
+            tuple.set_null("");
+        }
+        Err(err) => vec_table.set_error(&format!("can't receive: {}", err)),
+    }
+
+    return tuple;
+}
+
+const WS_CLOSE_ARGS: [&str; 1] = ["conn"];
+
+fn ws_close(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    if let Some(handle) = get_handle(table, "conn").and_then(|pos| vec_table.handles.get_mut(pos)) {
+        if let Handle::WebSocket(client) = handle {
+            // the other side may already be gone, it is closed either way
+            let _ = client.send_message(&OwnedMessage::Close(None));
+            let _ = client.shutdown();
+        }
+
+        *handle = Handle::Closed;
+    }
+
+    return Tuple::new();
+}
+
+const WS_LISTEN_ARGS: [&str; 4] = ["addr", "handler", "connections", "timeout"];
+
+// handler(message, conn) is called for each message, what it returns (if not null) is sent back
+// the connections are served one after the other, it only returns after the given amount of them
+// or once no connection came for timeout seconds
+fn ws_listen(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let addr = get_string(table, "addr");
+    let timeout = get_timeout(table);
+
+    let limit = match table.get("connections").kind {
+        Kind::Null => None,
+        _ => Some(get_bigint(table, "connections")),
+    };
+
+    let handler = match get_function(table, "handler") {
+        Some(handler) => handler,
+        None => {
+            vec_table.set_error("the handler isn't a function");
+            return Tuple::new();
+        }
+    };
+
+    let listener = match TcpListener::bind(&addr) {
+        Ok(listener) => listener,
+        Err(err) => {
+            vec_table.set_error(&format!("can't listen on {}: {}", addr, err));
+            return Tuple::new();
+        }
+    };
+
+    let mut served = BigInt::zero();
+
+    while limit.as_ref().is_none_or(|limit| served < *limit) {
+        let stream = match accept_stream(&listener, timeout) {
+            Ok(Some(stream)) => stream,
+            Ok(None) => break,
+            Err(err) => {
+                vec_table.set_error(&format!("ws_listen: {}", err));
+                return Tuple::new();
+            }
+        };
+
+        // a client that never finishes the handshake doesn't hold the server
+        if stream.set_read_timeout(Some(http::READ_TIMEOUT)).is_err() {
+            continue;
+        }
+
+        let client = match stream.into_ws() {
+            Ok(upgrade) => match upgrade.accept() {
+                Ok(client) => client,
+                Err(_) => continue,
+            },
+            Err(_) => continue, // not a websocket handshake
+        };
+
+        if client.stream_ref().set_read_timeout(None).is_err() {
+            continue;
+        }
+
+        served += 1;
+        let pos = vec_table.handles.add(Handle::WebSocket(client));
+
+        // until the other side or the handler closes it
+        while let Handle::WebSocket(client) = &mut vec_table.handles[pos] {
+            let text = match ws_receive(client) {
+                Ok((Some(text), _)) => text,
+                _ => break,
+            };
+
+            let mut arguments = Tuple::new();
+            arguments.set_string("", text);
+            arguments.set_handle("", pos);
+
+            let result = handler.run(&arguments, process, vec_table);
+
+            // exit() stops the server instead of waiting for the next message
+            if vec_table.error.is_some() || vec_table.exit.is_some() {
+                vec_table.handles.remove(pos);
+                return Tuple::new();
+            }
+
+            if result.len() > 0 && result.get(0).kind != Kind::Null {
+                let reply = result
+                    .get(0)
+                    .get_string(result.get_name(0), &result.table)
+                    .unwrap();
+
+                if let Handle::WebSocket(client) = &mut vec_table.handles[pos] {
+                    if client.send_message(&OwnedMessage::Text(reply)).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        vec_table.handles.remove(pos);
+    }
+
+    return Tuple::new();
+}
+
+// pings are answered on the way, the bool is true when the other side closed
+fn ws_receive(client: &mut Client<TcpStream>) -> Result<(Option<String>, bool), String> {
+    loop {
+        match client.recv_message() {
+            Ok(OwnedMessage::Text(text)) => return Ok((Some(text), false)),
+            Ok(OwnedMessage::Binary(bytes)) => {
+                return Ok((Some(String::from_utf8_lossy(&bytes).to_string()), false))
+            }
+            Ok(OwnedMessage::Ping(data)) => {
+                if let Err(err) = client.send_message(&OwnedMessage::Pong(data)) {
+                    return Err(err.to_string());
+                }
+            }
+            Ok(OwnedMessage::Pong(_)) => {}
+            Ok(OwnedMessage::Close(_)) | Err(WebSocketError::NoDataAvailable) => {
+                let _ = client.send_message(&OwnedMessage::Close(None));
+                return Ok((None, true));
+            }
+            Err(WebSocketError::IoError(err))
+                if err.kind() == std::io::ErrorKind::WouldBlock
+                    || err.kind() == std::io::ErrorKind::TimedOut =>
+            {
+                return Ok((None, false));
+            }
+            Err(err) => return Err(err.to_string()),
+        }
+    }
+}
+
+const CPU_COUNT_ARGS: [&str; 0] = [];
+
+// logical cores
+fn cpu_count() -> Tuple {
+    let mut system = System::new();
+    system.refresh_cpu();
+
+    let mut tuple = Tuple::new();
+    tuple.set_bigint("", BigInt::from(system.get_processors().len()));
+
+    return tuple;
+}
+
+const CPU_USAGE_ARGS: [&str; 0] = [];
+
+// in percent, measured over a short pause since the usage is a difference between two refreshes
+fn cpu_usage() -> Tuple {
+    let mut system = System::new();
+    system.refresh_cpu();
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    system.refresh_cpu();
+
+    let mut cores = Tuple::new();
+
+    for processor in system.get_processors() {
+        cores.set_number("", processor.get_cpu_usage() as f64);
+    }
+
+    let mut info = Tuple::new();
+    info.set_number(
+        "total",
+        system.get_global_processor_info().get_cpu_usage() as f64,
+    );
+    info.set_tuple("cores", cores);
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const MEMORY_ARGS: [&str; 0] = [];
+
+// sizes are in bytes, sysinfo gives kilobytes
+fn memory() -> Tuple {
+    let mut system = System::new();
+    system.refresh_memory();
+
+    let mut info = Tuple::new();
+    info.set_bigint("total", BigInt::from(system.get_total_memory()) * 1024);
+    info.set_bigint("used", BigInt::from(system.get_used_memory()) * 1024);
+    info.set_bigint(
+        "available",
+        BigInt::from(system.get_available_memory()) * 1024,
+    );
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const SWAP_ARGS: [&str; 0] = [];
+
+fn swap() -> Tuple {
+    let mut system = System::new();
+    system.refresh_memory();
+
+    let mut info = Tuple::new();
+    info.set_bigint("total", BigInt::from(system.get_total_swap()) * 1024);
+    info.set_bigint("used", BigInt::from(system.get_used_swap()) * 1024);
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const LOAD_AVERAGE_ARGS: [&str; 0] = [];
+
+// over the last 1, 5 and 15 minutes, always 0 on windows
+fn load_average() -> Tuple {
+    let load = System::new().get_load_average();
+
+    let mut info = Tuple::new();
+    info.set_number("one", load.one);
+    info.set_number("five", load.five);
+    info.set_number("fifteen", load.fifteen);
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const DISKS_ARGS: [&str; 0] = [];
+
+fn disks() -> Tuple {
+    let mut system = System::new();
+    system.refresh_disks_list();
+
+    let mut list = Tuple::new();
+
+    for disk in system.get_disks() {
+        let total = disk.get_total_space();
+        let available = disk.get_available_space();
+
+        let mut info = Tuple::new();
+        info.set_string("name", disk.get_name().to_string_lossy().to_string());
+        info.set_string(
+            "mount",
+            disk.get_mount_point().to_string_lossy().to_string(),
+        );
+        info.set_bigint("total", BigInt::from(total));
+        info.set_bigint("used", BigInt::from(total.saturating_sub(available)));
+        info.set_bigint("available", BigInt::from(available));
+
+        list.set_tuple("", info);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+const PROCESSES_ARGS: [&str; 0] = [];
+
+// sorted by pid, the cpu usage is measured like in cpu_usage()
+fn processes() -> Tuple {
+    let mut system = System::new();
+    system.refresh_processes();
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    system.refresh_processes();
+
+    let mut pids: Vec<&sysinfo::Pid> = system.get_processes().keys().collect();
+    pids.sort();
+
+    let mut list = Tuple::new();
+
+    for pid in pids {
+        let process = &system.get_processes()[pid];
+
+        let mut info = Tuple::new();
+        info.set_bigint("pid", BigInt::from(process.pid()));
+        info.set_string("name", process.name().to_string());
+        info.set_bigint("memory", BigInt::from(process.memory()) * 1024);
+        info.set_number("cpu", process.cpu_usage() as f64);
+
+        list.set_tuple("", info);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+const PID_ARGS: [&str; 0] = [];
+
+fn pid() -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_bigint("", BigInt::from(std::process::id()));
+
+    return tuple;
+}
+
+const NOW_ARGS: [&str; 0] = [];
+
+// seconds since the unix epoch
+fn now() -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_number("", get_now());
+
+    return tuple;
+}
+
+const MONOTONIC_ARGS: [&str; 0] = [];
+
+// seconds since the script started, never goes back unlike now()
+fn monotonic(vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+    tuple.set_number("", vec_table.start.elapsed().as_secs_f64());
+
+    return tuple;
+}
+
+const SLEEP_ARGS: [&str; 1] = ["seconds"];
+
+fn sleep(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let seconds = get_number(table, "seconds");
+
+    if seconds > 0.0 && seconds.is_finite() {
+        std::thread::sleep(std::time::Duration::from_secs_f64(seconds));
+    }
+
+    return Tuple::new();
+}
+
+const DATE_ARGS: [&str; 1] = ["ts"];
+
+// in utc, ts defaults to now()
+fn date(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let date = get_date(get_timestamp(table, "ts"));
+
+    let mut info = Tuple::new();
+    info.set_bigint("year", BigInt::from(date.0));
+    info.set_bigint("month", BigInt::from(date.1));
+    info.set_bigint("day", BigInt::from(date.2));
+    info.set_bigint("hour", BigInt::from(date.3));
+    info.set_bigint("minute", BigInt::from(date.4));
+    info.set_bigint("second", BigInt::from(date.5));
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const STRFTIME_ARGS: [&str; 2] = ["ts", "fmt"];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// the usual % directives in utc, an unknown one is kept as it is
+fn strftime(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let ts = get_timestamp(table, "ts");
+    let fmt = get_string(table, "fmt");
+    let (year, month, day, hour, minute, second, weekday, yday) = get_date(ts);
+
+    let mut string = String::with_capacity(fmt.len());
+    let mut chars = fmt.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            string.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('Y') => string += &year.to_string(),
+            Some('y') => string += &format!("{:02}", year.rem_euclid(100)),
+            Some('m') => string += &format!("{:02}", month),
+            Some('d') => string += &format!("{:02}", day),
+            Some('e') => string += &format!("{:2}", day),
+            Some('H') => string += &format!("{:02}", hour),
+            Some('I') => string += &format!("{:02}", (hour + 11) % 12 + 1),
+            Some('p') => string += if hour < 12 { "AM" } else { "PM" },
+            Some('M') => string += &format!("{:02}", minute),
+            Some('S') => string += &format!("{:02}", second),
+            Some('j') => string += &format!("{:03}", yday),
+            Some('a') => string += &WEEKDAYS[weekday as usize][..3],
+            Some('A') => string += WEEKDAYS[weekday as usize],
+            Some('b') => string += &MONTHS[month as usize - 1][..3],
+            Some('B') => string += MONTHS[month as usize - 1],
+            Some('F') => string += &format!("{}-{:02}-{:02}", year, month, day),
+            Some('T') => string += &format!("{:02}:{:02}:{:02}", hour, minute, second),
+            Some('s') => string += &ts.floor().to_string(),
+            Some('%') => string.push('%'),
+            Some(other) => {
+                string.push('%');
+                string.push(other);
+            }
+            None => string.push('%'),
+        }
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+const TIMEIT_ARGS: [&str; 2] = ["function", "arguments?"];
+
+// seconds taken by calling the function with the rest of the arguments
+fn timeit(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let arguments = match table.get("arguments").kind {
+        Kind::Null => Tuple::new(),
+        _ => get_tuple(table, "arguments"),
+    };
+
+    let function = match get_function(table, "function") {
+        Some(function) => function,
+        None => {
+            vec_table.set_error("the first argument of timeit isn't a function");
+            return Tuple::new();
+        }
+    };
+
+    let start = std::time::Instant::now();
+    function.run(&arguments, process, vec_table);
+
+    let mut tuple = Tuple::new();
+    tuple.set_number("", start.elapsed().as_secs_f64());
+
+    return tuple;
+}
+
+fn get_now() -> f64 {
+    match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(_) => 0.0,
+    }
+}
+
+fn get_timestamp(table: &mut Table, entry: &str) -> f64 {
+    match table.get(entry).kind {
+        Kind::Null => get_now(),
+        _ => get_number(table, entry),
+    }
+}
+
+// (year, month, day, hour, minute, second, weekday from sunday, day of the year) in utc
+#[allow(clippy::type_complexity)]
+fn get_date(ts: f64) -> (i64, i64, i64, i64, i64, i64, i64, i64) {
+    let secs = ts.floor() as i64;
+    let days = secs.div_euclid(86400);
+    let time = secs.rem_euclid(86400);
+
+    let (year, month, day) = get_civil(days);
+    let yday = days - get_days(year, 1, 1) + 1;
+
+    return (
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        (days + 4).rem_euclid(7),
+        yday,
+    );
+}
+
+// days since 1970-01-01 to (year, month, day), the proleptic gregorian calendar
+fn get_civil(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return (year, month, day);
+}
+
+// the other way around
+fn get_days(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    return era * 146097 + doe - 719468;
+}
+
+const ENV_ARGS: [&str; 1] = ["name"];
+
+// null if it isn't set
+fn env(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let name = get_string(table, "name");
+    let mut tuple = Tuple::new();
+
+    match std::env::var(&name) {
+        Ok(value) => tuple.set_string("", value),
+        Err(_) => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+const SET_ENV_ARGS: [&str; 2] = ["name", "value"];
+
+// a null value removes it, the commands run after get it too
+fn set_env(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let name = get_string(table, "name");
+
+    if name.is_empty() || name.contains('=') || name.contains('\0') {
+        vec_table.set_error(&format!("invalid environment variable name {:?}", name));
+        return Tuple::new();
+    }
+
+    match table.get("value").kind {
+        Kind::Null => std::env::remove_var(&name),
+        _ => std::env::set_var(&name, get_string(table, "value")),
+    }
+
+    return Tuple::new();
+}
+
+const ENV_ALL_ARGS: [&str; 0] = [];
+
+// named after each variable, sorted by name
+fn env_all() -> Tuple {
+    let mut vars: Vec<(String, String)> = std::env::vars_os()
+        .map(|(name, value)| {
+            (
+                name.to_string_lossy().to_string(),
+                value.to_string_lossy().to_string(),
+            )
+        })
+        .collect();
+    vars.sort();
+
+    let mut info = Tuple::new();
+
+    for (name, value) in vars {
+        info.set_string(&name, value);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const EXEC_ARGS: [&str; 2] = ["cmd", "args?"];
+
+// runs it without a shell and waits, gives (code, stdout, stderr), code is null if a signal stopped it
+fn exec(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let cmd = get_string(table, "cmd");
+    let args = get_command_args(table, "args");
+    let mut tuple = Tuple::new();
+
+    match std::process::Command::new(&cmd).args(&args).output() {
+        Ok(output) => {
+            let mut info = Tuple::new();
+            set_exit_code(&mut info, output.status);
+            info.set_string(
+                "stdout",
+                String::from_utf8_lossy(&output.stdout).to_string(),
+            );
+            info.set_string(
+                "stderr",
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            );
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) => vec_table.set_error(&format!("can't run {}: {}", cmd, err)),
+    }
+
+    return tuple;
+}
+
+const EXEC_STREAM_ARGS: [&str; 2] = ["cmd", "args?"];
+
+// like exec but each line of stdout is printed as soon as it comes, stderr goes to the script's stderr
+fn exec_stream(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let cmd = get_string(table, "cmd");
+    let args = get_command_args(table, "args");
+    let mut tuple = Tuple::new();
+
+    let child = std::process::Command::new(&cmd)
+        .args(&args)
+        .stdout(std::process::Stdio::piped())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            vec_table.set_error(&format!("can't run {}: {}", cmd, err));
+            return tuple;
+        }
+    };
+
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = BufReader::new(stdout);
+        let mut line = Vec::new();
+
+        while let Ok(n) = reader.read_until(b'\n', &mut line) {
+            if n == 0 {
+                break;
+            }
+
+            print!("{}", String::from_utf8_lossy(&line));
+            let _ = std::io::stdout().flush();
+            line.clear();
+        }
+    }
+
+    match child.wait() {
+        Ok(status) => set_exit_code(&mut tuple, status),
+        Err(err) => vec_table.set_error(&format!("can't wait for {}: {}", cmd, err)),
+    }
+
+    return tuple;
+}
+
+const EXIT_ARGS: [&str; 1] = ["code"];
+
+// every process stops and gives back its levels, main exits with the code after
+fn exit(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let code = get_bigint(table, "code").to_i32().unwrap_or(1);
+
+    vec_table.exit = Some(code);
+
+    return Tuple::new();
+}
+
+// each argument is a string, a tuple gives one argument per element
+fn get_command_args(table: &mut Table, entry: &str) -> Vec<String> {
+    let mut args = Vec::new();
+
+    if table.get(entry).kind == Kind::Null {
+        return args;
+    }
+
+    let tuple = get_tuple(table, entry);
+
+    for i in 0..(tuple.len()) {
+        let var = tuple.get(i);
+
+        if var.kind == Kind::Tuple {
+            let inner = tuple.table.get_tuple(var.pos);
+
+            for j in 0..(inner.len()) {
+                args.push(
+                    inner
+                        .get(j)
+                        .get_string(inner.get_name(j), &inner.table)
+                        .unwrap(),
+                );
+            }
+        } else {
+            args.push(var.get_string(tuple.get_name(i), &tuple.table).unwrap());
+        }
+    }
+
+    return args;
+}
+
+fn set_exit_code(tuple: &mut Tuple, status: std::process::ExitStatus) {
+    match status.code() {
+        Some(code) => tuple.set_bigint("code", BigInt::from(code)),
+        None => tuple.set_null("code"),
+    }
+}
+
+const RE_MATCH_ARGS: [&str; 2] = ["pattern", "text"];
+
+// the whole match then each group, named groups can be accessed by their name, null if it doesn't match
+fn re_match(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let pattern = get_string(table, "pattern");
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    let regex = match get_regex(vec_table, &pattern) {
+        Some(regex) => regex,
+        None => return tuple,
+    };
+
+    match regex.captures(&text) {
+        Some(captures) => tuple.set_tuple("", get_captures(&regex, &captures)),
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+const RE_FIND_ALL_ARGS: [&str; 2] = ["pattern", "text"];
+
+// the matched strings, or the groups of each match like re_match if the pattern has any
+fn re_find_all(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let pattern = get_string(table, "pattern");
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    let regex = match get_regex(vec_table, &pattern) {
+        Some(regex) => regex,
+        None => return tuple,
+    };
+
+    let mut list = Tuple::new();
+
+    for captures in regex.captures_iter(&text) {
+        if regex.captures_len() > 1 {
+            list.set_tuple("", get_captures(&regex, &captures));
+        } else {
+            list.set_string("", captures[0].to_string());
+        }
+    }
+
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+const RE_REPLACE_ARGS: [&str; 3] = ["pattern", "text", "replacement"];
+
+// a string replacement can use $1 or ${name}, a function is given the groups like re_match and returns the replacement
+fn re_replace(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let pattern = get_string(table, "pattern");
+    let text = get_string(table, "text");
+    let function = get_function(table, "replacement");
+    let replacement = get_string(table, "replacement");
+    let mut tuple = Tuple::new();
+
+    let regex = match get_regex(vec_table, &pattern) {
+        Some(regex) => regex,
+        None => return tuple,
+    };
+
+    let function = match function {
+        Some(function) => function,
+        None => {
+            tuple.set_string(
+                "",
+                regex.replace_all(&text, replacement.as_str()).to_string(),
+            );
+            return tuple;
+        }
+    };
+
+    let mut string = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for captures in regex.captures_iter(&text) {
+        let whole = captures.get(0).unwrap();
+
+        let mut arguments = Tuple::new();
+        arguments.set_tuple("", get_captures(&regex, &captures));
+
+        let result = function.run(&arguments, process, vec_table);
+
+        if vec_table.error.is_some() {
+            return tuple;
+        }
+
+        string += &text[last..whole.start()];
+
+        if result.len() > 0 {
+            string += &result
+                .get(0)
+                .get_string(result.get_name(0), &result.table)
+                .unwrap();
+        }
+
+        last = whole.end();
+    }
+
+    string += &text[last..];
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+const RE_SPLIT_ARGS: [&str; 2] = ["pattern", "text"];
+
+fn re_split(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let pattern = get_string(table, "pattern");
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    let regex = match get_regex(vec_table, &pattern) {
+        Some(regex) => regex,
+        None => return tuple,
+    };
+
+    let mut list = Tuple::new();
+
+    for part in regex.split(&text) {
+        list.set_string("", part.to_string());
+    }
+
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+// compiled once per pattern, an invalid one is an error
+const MAX_REGEXES: usize = 64; // a script building patterns on the fly doesn't keep all of them
+
+// the least recently used pattern is dropped when there are too many
+fn get_regex(vec_table: &mut VecTable, pattern: &str) -> Option<Regex> {
+    if let Some(pos) = vec_table.regexes.iter().position(|(key, _)| key == pattern) {
+        let entry = vec_table.regexes.remove(pos);
+        let regex = entry.1.clone();
+        vec_table.regexes.push(entry);
+
+        return Some(regex);
+    }
+
+    match Regex::new(pattern) {
+        Ok(regex) => {
+            if vec_table.regexes.len() == MAX_REGEXES {
+                vec_table.regexes.remove(0);
+            }
+
+            vec_table.regexes.push((pattern.to_string(), regex.clone()));
+            Some(regex)
+        }
+        Err(err) => {
+            vec_table.set_error(&format!("invalid regex: {}", err));
+            None
+        }
+    }
+}
+
+// a group that didn't take part in the match is null
+fn get_captures(regex: &Regex, captures: &regex::Captures) -> Tuple {
+    let mut tuple = Tuple::new();
+    let names: Vec<Option<&str>> = regex.capture_names().collect();
+
+    for i in 0..(names.len()) {
+        let name = names[i].unwrap_or("");
+
+        match captures.get(i) {
+            Some(group) => tuple.set_string(name, group.as_str().to_string()),
+            None => tuple.set_null(name),
+        }
+    }
+
+    return tuple;
+}
+
+const FORMAT_ARGS: [&str; 2] = ["template", "args?"];
+
+// {} takes the next argument, {0} one by position and {name} one by name (a variable or name = value),
+// after : comes [[fill]align][+][0][width][,][.precision][type] with align < > ^ and type x X b o e f
+fn format(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let template = get_string(table, "template");
+
+    let mut args = match table.get("args").kind {
+        Kind::Null => Tuple::new(),
+        _ => get_tuple(table, "args"),
+    };
+
+    // a single tuple gives its elements
+    if args.len() == 1 && args.get(0).kind == Kind::Tuple {
+        args = args.table.get_tuple(args.get(0).pos);
+    }
+
+    let chars: Vec<char> = template.chars().collect();
+    let mut string = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut tuple = Tuple::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+
+        if ch == '}' {
+            if i + 1 < chars.len() && chars[i + 1] == '}' {
+                i += 1;
+            }
+
+            string.push('}');
+            i += 1;
+            continue;
+        }
+
+        if ch != '{' {
+            string.push(ch);
+            i += 1;
+            continue;
+        }
+
+        if i + 1 < chars.len() && chars[i + 1] == '{' {
+            string.push('{');
+            i += 2;
+            continue;
+        }
+
+        let close = match chars[i..].iter().position(|c| *c == '}') {
+            Some(n) => i + n,
+            None => {
+                vec_table.set_error("unclosed { in format");
+                return tuple;
+            }
+        };
+
+        let placeholder: String = chars[(i + 1)..close].iter().collect();
+        let (key, spec) = match placeholder.find(':') {
+            Some(n) => (&placeholder[..n], &placeholder[(n + 1)..]),
+            None => (placeholder.as_str(), ""),
+        };
+        let key = key.trim();
+
+        let pos = if key.is_empty() {
+            next += 1;
+            Some(next - 1)
+        } else if let Ok(n) = key.parse::<usize>() {
+            Some(n)
+        } else {
+            (0..(args.len())).find(|n| {
+                let name = args.get_name(*n);
+                get_keyword(name).unwrap_or_else(|| get_real_name(name)) == key
+            })
+        };
+
+        match pos.filter(|pos| *pos < args.len()) {
+            Some(pos) => match format_value(args.get(pos), args.get_name(pos), &args.table, spec) {
+                Ok(value) => string += &value,
+                Err(err) => {
+                    vec_table.set_error(&err);
+                    return tuple;
+                }
+            },
+            None => {
+                vec_table.set_error(&format!("no argument for {{{}}} in format", placeholder));
+                return tuple;
+            }
+        }
+
+        i = close + 1;
+    }
+
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+fn format_value(var: &Variable, name: &str, table: &Table, spec: &str) -> Result<String, String> {
+    let chars: Vec<char> = spec.chars().collect();
+    let mut i = 0;
+
+    let mut fill = ' ';
+    let mut align = None;
+
+    if chars.len() > 1 && (chars[1] == '<' || chars[1] == '>' || chars[1] == '^') {
+        fill = chars[0];
+        align = Some(chars[1]);
+        i = 2;
+    } else if !chars.is_empty() && (chars[0] == '<' || chars[0] == '>' || chars[0] == '^') {
+        align = Some(chars[0]);
+        i = 1;
+    }
+
+    let plus = i < chars.len() && chars[i] == '+';
+    if plus {
+        i += 1;
+    }
+
+    let zero = i < chars.len() && chars[i] == '0';
+    if zero {
+        i += 1;
+    }
+
+    let mut width = 0;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        width = width * 10 + chars[i].to_digit(10).unwrap() as usize;
+        i += 1;
+    }
+
+    let thousands = i < chars.len() && (chars[i] == ',' || chars[i] == '_');
+    let separator = if thousands { chars[i] } else { ',' };
+    if thousands {
+        i += 1;
+    }
+
+    let mut precision = None;
+    if i < chars.len() && chars[i] == '.' {
+        i += 1;
+        let mut n = 0;
+
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            n = n * 10 + chars[i].to_digit(10).unwrap() as usize;
+            i += 1;
+        }
+
+        precision = Some(n);
+    }
+
+    let kind = if i < chars.len() {
+        Some(chars[i])
+    } else {
+        None
+    };
+
+    if i + 1 < chars.len() || kind.is_some_and(|k| !"xXboef".contains(k)) {
+        return Err(format!("invalid format spec {}", spec));
+    }
+
+    let numeric = var.kind == Kind::Number || var.kind == Kind::BigInt;
+
+    let mut value = match kind {
+        Some('x') | Some('X') | Some('b') | Some('o') => {
+            let bigint = match var.get_bigint(name, table) {
+                Ok(bigint)
+                    if var.kind != Kind::Number || table.get_number(var.pos).fract() == 0.0 =>
+                {
+                    bigint
+                }
+                _ => {
+                    return Err(format!(
+                        "{} can't be formatted with {}",
+                        var.get_string(name, table)?,
+                        spec
+                    ))
+                }
+            };
+
+            let radix = match kind {
+                Some('b') => 2,
+                Some('o') => 8,
+                _ => 16,
+            };
+
+            let digits = bigint.to_str_radix(radix);
+
+            if kind == Some('X') {
+                digits.to_uppercase()
+            } else {
+                digits
+            }
+        }
+        Some('e') => format!(
+            "{:.*e}",
+            precision.unwrap_or(6),
+            var.get_number(name, table)?
+        ),
+        Some('f') => format!(
+            "{:.*}",
+            precision.unwrap_or(6),
+            var.get_number(name, table)?
+        ),
+        _ => match precision {
+            Some(precision) if numeric => format!("{:.*}", precision, var.get_number(name, table)?),
+            Some(precision) => var
+                .get_string(name, table)?
+                .chars()
+                .take(precision)
+                .collect(),
+            None => var.get_string(name, table)?,
+        },
+    };
+
+    if thousands && numeric {
+        value = add_thousands(&value, separator);
+    }
+
+    if plus && numeric && !value.starts_with('-') {
+        value = format!("+{}", value);
+    }
+
+    let len = value.chars().count();
+
+    if len >= width {
+        return Ok(value);
+    }
+
+    // zeros go after the sign
+    if zero && align.is_none() && numeric {
+        let sign = if value.starts_with('-') || value.starts_with('+') {
+            1
+        } else {
+            0
+        };
+        return Ok(format!(
+            "{}{}{}",
+            &value[..sign],
+            "0".repeat(width - len),
+            &value[sign..]
+        ));
+    }
+
+    let padding = width - len;
+    let align = align.unwrap_or(if numeric { '>' } else { '<' });
+
+    let (left, right) = match align {
+        '>' => (padding, 0),
+        '^' => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    let fill = fill.to_string();
+
+    return Ok(format!(
+        "{}{}{}",
+        fill.repeat(left),
+        value,
+        fill.repeat(right)
+    ));
+}
+
+// 1234567.5 -> 1,234,567.5 only in the digits before the point
+fn add_thousands(value: &str, separator: char) -> String {
+    let start = if value.starts_with('-') || value.starts_with('+') {
+        1
+    } else {
+        0
+    };
+    let end = value[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(value.len(), |n| n + start);
+
+    let digits: Vec<char> = value[start..end].chars().collect();
+    let mut string = value[..start].to_string();
+
+    for i in 0..(digits.len()) {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            string.push(separator);
+        }
+
+        string.push(digits[i]);
+    }
+
+    string += &value[end..];
+
+    return string;
+}
+
+// the name of a keyword argument without KEYWORD_PREFIX, None if it isn't one
+fn get_keyword(name: &str) -> Option<&str> {
+    return get_real_name(name).strip_prefix(KEYWORD_PREFIX);
+}
+
+const STR_ARGS: [&str; 1] = ["value"];
+
+fn str(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+
+    let mut tuple = Tuple::new();
+    tuple.set_string("", get_string(table, "value"));
+
+    return tuple;
+}
+
+const FLOAT_ARGS: [&str; 1] = ["value"];
+
+// a string is parsed, "inf" and "nan" included
+fn float(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    let value = match var.kind {
+        Kind::String => {
+            let string = get_string(table, "value");
+            string
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("{:?} isn't a number", string))
+        }
+        _ => var.get_number("value", table),
+    };
+
+    match value {
+        Ok(value) => tuple.set_number("", value),
+        Err(err) => vec_table.set_error(&format!("float: {}", err)),
+    }
+
+    return tuple;
+}
+
+const BOOL_ARGS: [&str; 1] = ["value"];
+
+// only "true" and "false" for a string, like a condition for the rest
+fn bool(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    let value = match var.kind {
+        Kind::String => {
+            let string = get_string(table, "value");
+            string
+                .trim()
+                .parse::<bool>()
+                .map_err(|_| format!("{:?} isn't a bool", string))
+        }
+        _ => var.get_bool("value", table),
+    };
+
+    match value {
+        Ok(value) => tuple.set_bool("", value),
+        Err(err) => vec_table.set_error(&format!("bool: {}", err)),
+    }
+
+    return tuple;
+}
+
+const BIGINT_ARGS: [&str; 2] = ["value", "base"];
+
+// a string is read in the base (10 by default, or given by a 0x 0b 0o prefix), a number loses its fraction
+fn bigint(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    let base = match table.get("base").kind {
+        Kind::Null => None,
+        _ => match get_bigint(table, "base")
+            .to_u32()
+            .filter(|base| (2..=36).contains(base))
+        {
+            Some(base) => Some(base),
+            None => {
+                vec_table.set_error("bigint: the base has to be from 2 to 36");
+                return tuple;
+            }
+        },
+    };
+
+    let value = match var.kind {
+        Kind::String => parse_bigint(&get_string(table, "value"), base),
+        Kind::Number => {
+            let number = table.get_number(var.pos);
+
+            if number.is_finite() {
+                Ok(BigInt::from_f64(number.trunc()).unwrap())
+            } else {
+                Err(format!("{} can't be an integer", number))
+            }
+        }
+        _ => var.get_bigint("value", table),
+    };
+
+    match value {
+        Ok(value) => tuple.set_bigint("", value),
+        Err(err) => vec_table.set_error(&format!("bigint: {}", err)),
+    }
+
+    return tuple;
+}
+
+const TO_RADIX_ARGS: [&str; 2] = ["num", "base"];
+
+// the digits of an integer in the base, lowercase after 9
+fn to_radix(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("num").clone();
+    let mut tuple = Tuple::new();
+
+    let base = match get_bigint(table, "base")
+        .to_u32()
+        .filter(|base| (2..=36).contains(base))
+    {
+        Some(base) => base,
+        None => {
+            vec_table.set_error("to_radix: the base has to be from 2 to 36");
+            return tuple;
+        }
+    };
+
+    let num = match var.kind {
+        Kind::BigInt => table.get_bigint(var.pos),
+        Kind::Number if table.get_number(var.pos).fract() == 0.0 => {
+            BigInt::from_f64(table.get_number(var.pos)).unwrap()
+        }
+        _ => {
+            let value = get_string(table, "num");
+            vec_table.set_error(&format!("to_radix: {} isn't an integer", value));
+            return tuple;
+        }
+    };
+
+    tuple.set_string("", num.to_str_radix(base));
+
+    return tuple;
+}
+
+const TUPLE_ARGS: [&str; 1] = ["value"];
+
+// the characters of a string, the values of a range or an iterator, a tuple stays the same
+fn tuple(vec_table: &mut VecTable) -> Tuple {
+    let list = get_list(vec_table, "value");
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+// the elements of anything that can be iterated, anything else is alone in it
+fn get_list(vec_table: &mut VecTable, entry: &str) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get(entry).clone();
+    let mut list = Tuple::new();
+
+    match var.kind {
+        Kind::Tuple => list = table.get_tuple(var.pos),
+        Kind::String => {
+            for ch in table.get_string(var.pos).chars() {
+                list.set_string("", ch.to_string());
+            }
+        }
+        Kind::Range => {
+            let range = table.get_range(var.pos);
+            let mut pos = BigInt::zero();
+
+            while pos < range.len() {
+                list.set_bigint("", range.get(&pos));
+                pos += 1;
+            }
+        }
+        Kind::Iterator => {
+            let pos = table.get_iterator(var.pos);
+
+            while let Some(value) = vec_table.next_iterator(pos) {
+                list.push(value.get(0), value.get_name(0), &value.table);
+            }
+        }
+        Kind::Null => {}
+        _ => list.push(&var, "", table),
+    }
+
+    return list;
+}
+
+const REPR_ARGS: [&str; 1] = ["value"];
+
+// the literal that gives the value back, strings are quoted and named elements written as name = value
+fn repr(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let var = table.get("value").clone();
+    let mut tuple = Tuple::new();
+
+    match get_repr(&var, "value", table) {
+        Ok(string) => tuple.set_string("", string),
+        Err(err) => vec_table.set_error(&format!("repr: {}", err)),
+    }
+
+    return tuple;
+}
+
+fn get_repr(var: &Variable, name: &str, table: &Table) -> Result<String, String> {
+    match var.kind {
+        Kind::String => {
+            let mut string = String::from("\"");
+
+            for ch in table.get_string(var.pos).chars() {
+                match ch {
+                    '"' => string += "\\\"",
+                    '\\' => string += "\\\\",
+                    '\n' => string += "\\n",
+                    '\t' => string += "\\t",
+                    '\r' => string += "\\r",
+                    _ => string.push(ch),
+                }
+            }
+
+            string.push('"');
+            return Ok(string);
+        }
+        Kind::Number => {
+            let number = table.get_number(var.pos);
+
+            if !number.is_finite() {
+                return Ok(format!("float(\"{}\")", number));
+            }
+
+            // so it doesn't come back as a bigint
+            let string = number.to_string();
+
+            if string.contains('.') || string.contains('e') {
+                return Ok(string);
+            }
+
+            return Ok(format!("{}.0", string));
+        }
+        Kind::Tuple => {
+            let tuple = table.get_tuple(var.pos);
+            let mut elements = Vec::with_capacity(tuple.len());
+
+            for i in 0..(tuple.len()) {
+                let name = get_real_name(tuple.get_name(i));
+                let value = get_repr(tuple.get(i), tuple.get_name(i), &tuple.table)?;
+
+                if name.is_empty() {
+                    elements.push(value);
+                } else {
+                    elements.push(format!("{} = {}", name, value));
+                }
+            }
+
+            return Ok(format!("({})", elements.join(", ")));
+        }
+        Kind::BigInt | Kind::Bool | Kind::Range => return var.get_string(name, table),
+        Kind::Null => return Ok(String::from("null")),
+        _ => return Err(format!("a {} has no literal", var.kind)),
+    }
+}
+
+// a sign, then a prefix if there is no base, then the digits
+fn parse_bigint(string: &str, base: Option<u32>) -> Result<BigInt, String> {
+    let trimmed = string.trim();
+
+    let (negative, digits) = match trimmed.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+
+    let (base, digits) = match base {
+        Some(base) => (base, digits),
+        None => {
+            let lower = digits.to_lowercase();
+
+            if lower.starts_with("0x") {
+                (16, &digits[2..])
+            } else if lower.starts_with("0b") {
+                (2, &digits[2..])
+            } else if lower.starts_with("0o") {
+                (8, &digits[2..])
+            } else {
+                (10, digits)
+            }
+        }
+    };
+
+    let digits = digits.replace('_', "");
+
+    let value = match BigInt::parse_bytes(digits.as_bytes(), base) {
+        Some(value) if !digits.starts_with('-') && !digits.starts_with('+') => value,
+        _ => return Err(format!("{:?} isn't an integer in base {}", string, base)),
+    };
+
+    if negative {
+        return Ok(-value);
+    }
+
+    return Ok(value);
+}
+
+const PUSH_ARGS: [&str; 2] = ["tuple", "values?"];
+
+// a new tuple with the values after the elements
+fn push(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut list = get_tuple(table, "tuple");
+    let values = get_tuple(table, "values");
+
+    for i in 0..(values.len()) {
+        list.push(values.get(i), values.get_name(i), &values.table);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+const POP_ARGS: [&str; 1] = ["tuple"];
+
+// (rest, last) the tuple without its last element and that element, null if it was empty
+fn pop(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let list = get_tuple(table, "tuple");
+    let mut rest = Tuple::new();
+    let mut info = Tuple::new();
+
+    for i in 0..(list.len().saturating_sub(1)) {
+        rest.push(list.get(i), list.get_name(i), &list.table);
+    }
+
+    info.set_tuple("rest", rest);
+
+    if list.len() > 0 {
+        let last = list.len() - 1;
+        info.push(list.get(last), "last", &list.table);
+    } else {
+        info.set_null("last");
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", info);
+
+    return tuple;
+}
+
+const CONCAT_ARGS: [&str; 1] = ["tuples?"];
+
+// the elements of every tuple one after the other, anything else is added as is
+fn concat(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let tuples = get_tuple(table, "tuples");
+    let mut list = Tuple::new();
+
+    for i in 0..(tuples.len()) {
+        if tuples.get(i).kind == Kind::Tuple {
+            let elements = tuples.table.get_tuple(tuples.get(i).pos);
+
+            for n in 0..(elements.len()) {
+                list.push(elements.get(n), elements.get_name(n), &elements.table);
+            }
+        } else {
+            list.push(tuples.get(i), "", &tuples.table);
+        }
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list);
+
+    return tuple;
+}
+
+const REVERSE_ARGS: [&str; 1] = ["value"];
+
+// the elements of a tuple or the characters of a string in the other order
+fn reverse(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    if table.get("value").kind == Kind::String {
+        tuple.set_string("", get_string(table, "value").chars().rev().collect());
+        return tuple;
+    }
+
+    let list = get_list(vec_table, "value");
+    let mut reversed = Tuple::new();
+
+    for i in (0..(list.len())).rev() {
+        reversed.push(list.get(i), list.get_name(i), &list.table);
+    }
+
+    tuple.set_tuple("", reversed);
+
+    return tuple;
+}
+
+const SORT_ARGS: [&str; 2] = ["tuple", "key"];
+
+// the elements in increasing order like < would put them, equal elements keep their order
+fn sort(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let key = get_function(table, "key");
+
+    let list = get_list(vec_table, "tuple");
+    let mut tuple = Tuple::new();
+
+    match get_sorted(vec_table, process, &list, key) {
+        Ok(sorted) => tuple.set_tuple("", sorted),
+        Err(err) => vec_table.set_error(&format!("sort: {}", err)),
+    }
+
+    return tuple;
+}
+
+const SORTED_ARGS: [&str; 2] = ["values", "key"];
+
+// like sort but takes anything that can be iterated
+fn sorted(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let key = get_function(table, "key");
+
+    let list = get_list(vec_table, "values");
+    let mut tuple = Tuple::new();
+
+    match get_sorted(vec_table, process, &list, key) {
+        Ok(sorted) => tuple.set_tuple("", sorted),
+        Err(err) => vec_table.set_error(&format!("sorted: {}", err)),
+    }
+
+    return tuple;
+}
+
+fn get_sorted(
+    vec_table: &mut VecTable,
+    process: &Process,
+    list: &Tuple,
+    key: Option<Function>,
+) -> Result<Tuple, String> {
+    // the value each element is compared by, the element itself without a key function
+    let mut keys = Tuple::new();
+
+    for i in 0..(list.len()) {
+        match &key {
+            Some(function) => {
+                let mut arguments = Tuple::new();
+                arguments.push(list.get(i), "", &list.table);
+
+                let result = function.run(&arguments, process, vec_table);
+
+                if vec_table.error.is_some() {
+                    return Ok(Tuple::new());
+                }
+
+                if result.len() > 0 {
+                    keys.push(result.get(0), "", &result.table);
+                } else {
+                    keys.set_null("");
+                }
+            }
+            None => keys.push(list.get(i), "", &list.table),
+        }
+    }
+
+    let mut order: Vec<usize> = (0..(list.len())).collect();
+    let mut err = None;
+
+    order.sort_by(|&a, &b| {
+        match compare(
+            keys.get(a),
+            keys.get(b),
+            keys.get_name(a),
+            keys.get_name(b),
+            &keys.table,
+        ) {
+            Some(ordering) => ordering,
+            None => {
+                err = Some(format!(
+                    "can't compare {} and {}",
+                    keys.get(a).kind.get_str(),
+                    keys.get(b).kind.get_str()
+                ));
+                Ordering::Equal
+            }
+        }
+    });
+
+    if let Some(err) = err {
+        return Err(err);
+    }
+
+    let mut sorted = Tuple::new();
+
+    for i in 0..(order.len()) {
+        sorted.push(list.get(order[i]), list.get_name(order[i]), &list.table);
+    }
+
+    return Ok(sorted);
+}
+
+const UNIQUE_ARGS: [&str; 1] = ["tuple"];
+
+// the elements without the ones equal to an element before them
+fn unique(vec_table: &mut VecTable) -> Tuple {
+    let list = get_list(vec_table, "tuple");
+    let mut list_unique = Tuple::new();
+
+    for i in 0..(list.len()) {
+        if get_index_of(&list_unique, &get_item(&list, i)).is_none() {
+            list_unique.push(list.get(i), list.get_name(i), &list.table);
+        }
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", list_unique);
+
+    return tuple;
+}
+
+const INDEX_OF_ARGS: [&str; 2] = ["tuple", "value"];
+
+// the position of the first element equal to the value, null if there is none
+fn index_of(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let list = get_tuple(table, "tuple");
+    let mut tuple = Tuple::new();
+
+    match get_index_of(&list, &get_element(table, "value")) {
+        Some(pos) => tuple.set_bigint("", BigInt::from(pos)),
+        None => tuple.set_null(""),
+    }
+
+    return tuple;
+}
+
+// the element alone in a tuple so it can be compared with ==
+fn get_item(list: &Tuple, pos: usize) -> Tuple {
+    let mut item = Tuple::new();
+    item.push(list.get(pos), list.get_name(pos), &list.table);
+
+    return item;
+}
+
+fn get_element(table: &Table, entry: &str) -> Tuple {
+    let mut item = Tuple::new();
+    item.push(table.get(entry), "", table);
+
+    return item;
+}
+
+fn get_index_of(list: &Tuple, item: &Tuple) -> Option<usize> {
+    (0..(list.len())).find(|&i| get_item(list, i) == *item)
+}
+
+const SUM_ARGS: [&str; 1] = ["values?"];
+
+// the integers stay exact, a single number in the values makes the sum a number
+fn sum(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut values = get_tuple(table, "values");
+    let mut tuple = Tuple::new();
+
+    if values.len() == 1 && values.get(0).kind == Kind::Tuple {
+        values = values.table.get_tuple(values.get(0).pos);
+    }
+
+    let mut total_bigint = BigInt::zero();
+    let mut total_number = 0.0;
+    let mut float = false;
+
+    for i in 0..(values.len()) {
+        let var = values.get(i);
+
+        match var.kind {
+            Kind::BigInt => total_bigint += values.table.get_bigint(var.pos),
+            Kind::Number => {
+                total_number += values.table.get_number(var.pos);
+                float = true;
+            }
+            _ => {
+                vec_table.set_error(&format!("sum: can't add {}", var.kind.get_str()));
+                return tuple;
+            }
+        }
+    }
+
+    if float {
+        tuple.set_number("", total_number + total_bigint.to_f64().unwrap_or(f64::NAN));
+    } else {
+        tuple.set_bigint("", total_bigint);
+    }
+
+    return tuple;
+}
+
+const ZIP_ARGS: [&str; 1] = ["tuples?"];
+
+// a tuple of the elements at each position, as long as the shortest tuple
+fn zip(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let tuples = get_tuple(table, "tuples");
+    let mut lists = Vec::new();
+
+    for i in 0..(tuples.len()) {
+        let mut list = Tuple::new();
+
+        if tuples.get(i).kind == Kind::Tuple {
+            list = tuples.table.get_tuple(tuples.get(i).pos);
+        } else {
+            list.push(tuples.get(i), "", &tuples.table);
+        }
+
+        lists.push(list);
+    }
+
+    let len = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+    let mut zipped = Tuple::new();
+
+    for pos in 0..len {
+        let mut item = Tuple::new();
+
+        for i in 0..(lists.len()) {
+            item.push(lists[i].get(pos), "", &lists[i].table);
+        }
+
+        zipped.set_tuple("", item);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", zipped);
+
+    return tuple;
+}
+
+const ENUMERATE_FN_ARGS: [&str; 1] = ["tuple"];
+
+// (index, value) for each element
+fn enumerate(vec_table: &mut VecTable) -> Tuple {
+    let list = get_list(vec_table, "tuple");
+    let mut enumerated = Tuple::new();
+
+    for i in 0..(list.len()) {
+        let mut item = Tuple::new();
+        item.set_bigint("index", BigInt::from(i));
+        item.push(list.get(i), "value", &list.table);
+
+        enumerated.set_tuple("", item);
+    }
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", enumerated);
+
+    return tuple;
+}
+
+const MAP_ARGS: [&str; 2] = ["tuple", "function"];
+
+// what the function returns for each element
+fn map(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let function = get_function(table, "function");
+
+    let list = get_list(vec_table, "tuple");
+    let mut tuple = Tuple::new();
+
+    let function = match function {
+        Some(function) => function,
+        None => {
+            vec_table.set_error("map: the second argument must be a function");
+            return tuple;
+        }
+    };
+
+    let mut mapped = Tuple::new();
+
+    for i in 0..(list.len()) {
+        let mut arguments = Tuple::new();
+        arguments.push(list.get(i), "", &list.table);
+
+        let result = function.run(&arguments, process, vec_table);
+
+        if vec_table.error.is_some() {
+            return tuple;
+        }
+
+        if result.len() > 0 {
+            mapped.push(result.get(0), list.get_name(i), &result.table);
+        } else {
+            mapped.set_null(list.get_name(i));
+        }
+    }
+
+    tuple.set_tuple("", mapped);
+
+    return tuple;
+}
+
+const FILTER_ARGS: [&str; 2] = ["tuple", "function"];
+
+// the elements the function returns true for
+fn filter(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let function = get_function(table, "function");
+
+    let list = get_list(vec_table, "tuple");
+    let mut tuple = Tuple::new();
+
+    let function = match function {
+        Some(function) => function,
+        None => {
+            vec_table.set_error("filter: the second argument must be a function");
+            return tuple;
+        }
+    };
+
+    let mut filtered = Tuple::new();
+
+    for i in 0..(list.len()) {
+        let mut arguments = Tuple::new();
+        arguments.push(list.get(i), "", &list.table);
+
+        let result = function.run(&arguments, process, vec_table);
+
+        if vec_table.error.is_some() {
+            return tuple;
+        }
+
+        let keep = result.len() > 0
+            && result
+                .get(0)
+                .get_bool(result.get_name(0), &result.table)
+                .unwrap_or(false);
+
+        if keep {
+            filtered.push(list.get(i), list.get_name(i), &list.table);
+        }
+    }
+
+    tuple.set_tuple("", filtered);
+
+    return tuple;
+}
+
+const REDUCE_ARGS: [&str; 3] = ["tuple", "function", "initial"];
+
+// function(total, element) for each element, the first element is the start without an initial value
+fn reduce(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let function = get_function(table, "function");
+    let mut total = Tuple::new();
+    let mut start = 0;
+
+    if table.get("initial").kind != Kind::Null {
+        total.push(table.get("initial"), "", table);
+    }
+
+    let list = get_list(vec_table, "tuple");
+
+    let function = match function {
+        Some(function) => function,
+        None => {
+            vec_table.set_error("reduce: the second argument must be a function");
+            return Tuple::new();
+        }
+    };
+
+    if total.len() == 0 {
+        if list.len() == 0 {
+            vec_table.set_error("reduce: empty tuple without an initial value");
+            return total;
+        }
+
+        total.push(list.get(0), "", &list.table);
+        start = 1;
+    }
+
+    for i in start..(list.len()) {
+        let mut arguments = Tuple::new();
+        arguments.push(total.get(0), "", &total.table);
+        arguments.push(list.get(i), "", &list.table);
+
+        let result = function.run(&arguments, process, vec_table);
+
+        if vec_table.error.is_some() {
+            return Tuple::new();
+        }
+
+        total = Tuple::new();
+
+        if result.len() > 0 {
+            total.push(result.get(0), "", &result.table);
+        } else {
+            total.set_null("");
+        }
+    }
+
+    return total;
+}
+
+const TERM_SIZE_ARGS: [&str; 0] = [];
+
+// (width, height) in characters
+#[cfg(target_family = "unix")]
+fn term_size(vec_table: &mut VecTable) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match termion::terminal_size() {
+        Ok((width, height)) => {
+            let mut info = Tuple::new();
+            info.set_bigint("width", BigInt::from(width));
+            info.set_bigint("height", BigInt::from(height));
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) => vec_table.set_error(&format!("term_size: {}", err)),
+    }
+
+    return tuple;
+}
+
+#[cfg(target_family = "windows")]
+fn term_size(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "term_size");
+}
+
+const CLEAR_ARGS: [&str; 0] = [];
+
+// empties the screen and puts the cursor back at the top left
+#[cfg(target_family = "unix")]
+fn clear() -> Tuple {
+    print!("{}{}", termion::clear::All, termion::cursor::Goto(1, 1));
+    let _ = std::io::stdout().flush();
+
+    return Tuple::new();
+}
+
+#[cfg(target_family = "windows")]
+fn clear(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "clear");
+}
+
+const MOVE_CURSOR_ARGS: [&str; 2] = ["x", "y"];
+
+// (0, 0) is the top left like every other index
+#[cfg(target_family = "unix")]
+fn move_cursor(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let x = get_bigint(table, "x").to_u16().filter(|x| *x < u16::MAX);
+    let y = get_bigint(table, "y").to_u16().filter(|y| *y < u16::MAX);
+
+    match (x, y) {
+        (Some(x), Some(y)) => {
+            print!("{}", termion::cursor::Goto(x + 1, y + 1));
+            let _ = std::io::stdout().flush();
+        }
+        _ => vec_table.set_error("move_cursor: the position is outside of the terminal"),
+    }
+
+    return Tuple::new();
+}
+
+#[cfg(target_family = "windows")]
+fn move_cursor(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "move_cursor");
+}
+
+const COLOR_ARGS: [&str; 3] = ["text", "fg", "bg"];
+
+// the text written in the colors, a color is a name, a number up to 255 or (r, g, b)
+#[cfg(target_family = "unix")]
+fn color(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    let fg = match get_color(table, "fg") {
+        Ok(color) => color,
+        Err(err) => {
+            vec_table.set_error(&format!("color: {}", err));
+            return tuple;
+        }
+    };
+
+    let bg = match get_color(table, "bg") {
+        Ok(color) => color,
+        Err(err) => {
+            vec_table.set_error(&format!("color: {}", err));
+            return tuple;
+        }
+    };
+
+    let mut string = String::new();
+
+    if let Some((fg, _)) = &fg {
+        string += fg;
+    }
+
+    if let Some((_, bg)) = &bg {
+        string += bg;
+    }
+
+    string += &text;
+
+    // only the colors that were set are reset so it can be inside bold() or underline()
+    if fg.is_some() {
+        string += &Fg(termion::color::Reset).to_string();
+    }
+
+    if bg.is_some() {
+        string += &Bg(termion::color::Reset).to_string();
+    }
+
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+#[cfg(target_family = "windows")]
+fn color(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "color");
+}
+
+#[cfg(target_family = "unix")]
+const COLORS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "light_black",
+    "light_red",
+    "light_green",
+    "light_yellow",
+    "light_blue",
+    "light_magenta",
+    "light_cyan",
+    "light_white",
+];
+
+// the sequences that set it as (foreground, background), None for null so the color stays the one of the terminal
+#[cfg(target_family = "unix")]
+fn get_color(table: &mut Table, entry: &str) -> Result<Option<(String, String)>, String> {
+    let var = table.get(entry).clone();
+
+    match var.kind {
+        Kind::Null => Ok(None),
+        Kind::String => {
+            let name = table.get_string(var.pos);
+
+            match COLORS.iter().position(|color| *color == name) {
+                Some(pos) => Ok(Some(get_color_sequences(AnsiValue(pos as u8)))),
+                None => Err(format!("unknown color \"{}\"", name)),
+            }
+        }
+        Kind::BigInt => match table.get_bigint(var.pos).to_u8() {
+            Some(value) => Ok(Some(get_color_sequences(AnsiValue(value)))),
+            None => Err(String::from("a color number goes from 0 to 255")),
+        },
+        Kind::Tuple => {
+            let rgb = table.get_tuple(var.pos);
+            let mut values = [0; 3];
+
+            if rgb.len() != 3 {
+                return Err(String::from("a color tuple is (r, g, b)"));
+            }
+
+            for i in 0..3 {
+                match rgb.get(i).get_bigint(rgb.get_name(i), &rgb.table) {
+                    Ok(value) if value.to_u8().is_some() => values[i] = value.to_u8().unwrap(),
+                    _ => return Err(String::from("each part of a color goes from 0 to 255")),
+                }
+            }
+
+            Ok(Some(get_color_sequences(Rgb(
+                values[0], values[1], values[2],
+            ))))
+        }
+        _ => Err(format!("a color can't be a {}", var.kind.get_str())),
+    }
+}
+
+#[cfg(target_family = "unix")]
+fn get_color_sequences<C: Color + Copy>(color: C) -> (String, String) {
+    (Fg(color).to_string(), Bg(color).to_string())
+}
+
+const BOLD_ARGS: [&str; 1] = ["text"];
+
+#[cfg(target_family = "unix")]
+fn bold(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    tuple.set_string(
+        "",
+        format!(
+            "{}{}{}",
+            termion::style::Bold,
+            get_string(table, "text"),
+            termion::style::Reset
+        ),
+    );
+
+    return tuple;
+}
+
+#[cfg(target_family = "windows")]
+fn bold(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "bold");
+}
+
+const UNDERLINE_ARGS: [&str; 1] = ["text"];
+
+#[cfg(target_family = "unix")]
+fn underline(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    tuple.set_string(
+        "",
+        format!(
+            "{}{}{}",
+            termion::style::Underline,
+            get_string(table, "text"),
+            termion::style::NoUnderline
+        ),
+    );
+
+    return tuple;
+}
+
+#[cfg(target_family = "windows")]
+fn underline(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "underline");
+}
+
+const SHOW_CURSOR_ARGS: [&str; 0] = [];
+
+#[cfg(target_family = "unix")]
+fn show_cursor() -> Tuple {
+    print!("{}", termion::cursor::Show);
+    let _ = std::io::stdout().flush();
+
+    return Tuple::new();
+}
+
+#[cfg(target_family = "windows")]
+fn show_cursor(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "show_cursor");
+}
+
+const HIDE_CURSOR_ARGS: [&str; 0] = [];
+
+#[cfg(target_family = "unix")]
+fn hide_cursor() -> Tuple {
+    print!("{}", termion::cursor::Hide);
+    let _ = std::io::stdout().flush();
+
+    return Tuple::new();
+}
+
+#[cfg(target_family = "windows")]
+fn hide_cursor(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "hide_cursor");
+}
+
+const GETKEY_ARGS: [&str; 1] = ["timeout"];
+
+// the key pressed without waiting for enter, null once the timeout in seconds is over
+#[cfg(target_family = "unix")]
+fn getkey(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    let timeout = if table.get("timeout").kind == Kind::Null {
+        None
+    } else {
+        Some(std::time::Duration::from_secs_f64(
+            get_number(table, "timeout").max(0.0),
+        ))
+    };
+
+    if vec_table.keys.is_none() {
+        if let Err(err) = termion::get_tty() {
+            vec_table.set_error(&format!("getkey: no terminal: {}", err));
+            return tuple;
+        }
+
+        vec_table.keys = Some(termion::async_stdin());
+    }
+
+    let _raw = match std::io::stdout().into_raw_mode() {
+        Ok(raw) => raw,
+        Err(err) => {
+            vec_table.set_error(&format!("getkey: {}", err));
+            return tuple;
+        }
+    };
+
+    let start = std::time::Instant::now();
+    let mut escape = None;
+
+    loop {
+        let mut buffer = [0; 64];
+
+        if let Some(reader) = &mut vec_table.keys {
+            if let Ok(len) = std::io::Read::read(reader, &mut buffer) {
+                vec_table.pending_keys.extend_from_slice(&buffer[..len]);
+            }
+        }
+
+        let pending = &mut vec_table.pending_keys;
+
+        // the rest of an escape sequence can come a bit after the escape
+        if pending.len() == 1 && pending[0] == 0x1B {
+            let since = *escape.get_or_insert_with(std::time::Instant::now);
+
+            if since.elapsed() >= std::time::Duration::from_millis(20) {
+                pending.clear();
+                tuple.set_string("", String::from("esc"));
+                return tuple;
+            }
+        } else if pending.len() > 0 {
+            let mut rest = pending[1..].iter().map(|byte| Ok(*byte));
+            let event = termion::event::parse_event(pending[0], &mut rest);
+            let used = pending.len() - rest.len();
+
+            pending.drain(..used);
+
+            if let Ok(termion::event::Event::Key(key)) = event {
+                tuple.set_string("", get_key_name(key));
+                return tuple;
+            }
+
+            continue;
+        }
+
+        if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+            tuple.set_null("");
+            return tuple;
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+}
+
+#[cfg(target_family = "windows")]
+fn getkey(vec_table: &mut VecTable) -> Tuple {
+    return no_terminal(vec_table, "getkey");
+}
+
+// a character is itself, every other key has a name
+#[cfg(target_family = "unix")]
+fn get_key_name(key: termion::event::Key) -> String {
+    use termion::event::Key;
+
+    match key {
+        Key::Char('\n') => String::from("enter"),
+        Key::Char('\t') => String::from("tab"),
+        Key::Char(ch) => ch.to_string(),
+        Key::Alt(ch) => format!("alt+{}", ch),
+        Key::Ctrl(ch) => format!("ctrl+{}", ch),
+        Key::F(num) => format!("f{}", num),
+        Key::Backspace => String::from("backspace"),
+        Key::Left => String::from("left"),
+        Key::Right => String::from("right"),
+        Key::Up => String::from("up"),
+        Key::Down => String::from("down"),
+        Key::Home => String::from("home"),
+        Key::End => String::from("end"),
+        Key::PageUp => String::from("page_up"),
+        Key::PageDown => String::from("page_down"),
+        Key::BackTab => String::from("backtab"),
+        Key::Delete => String::from("delete"),
+        Key::Insert => String::from("insert"),
+        Key::Esc => String::from("esc"),
+        _ => String::from("null"),
+    }
+}
+
+#[cfg(target_family = "windows")]
+fn no_terminal(vec_table: &mut VecTable, name: &str) -> Tuple {
+    vec_table.set_error(&format!(
+        "{}: the terminal functions only work on unix",
+        name
+    ));
+    return Tuple::new();
+}
+
+const BASE64_ENCODE_ARGS: [&str; 1] = ["data?"];
+
+fn base64_encode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => tuple.set_string("", STANDARD.encode(&bytes)),
+        Err(err) => vec_table.set_error(&format!("base64_encode: {}", err)),
+    }
+
+    return tuple;
+}
+
+const BASE64_DECODE_ARGS: [&str; 1] = ["text"];
+
+// the bytes, from_utf8() turns them back into a string
+fn base64_decode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    match STANDARD.decode(text.trim()) {
+        Ok(bytes) => tuple.set_tuple("", get_byte_tuple(&bytes)),
+        Err(err) => vec_table.set_error(&format!("base64_decode: {}", err)),
+    }
+
+    return tuple;
+}
+
+const HEX_ENCODE_ARGS: [&str; 1] = ["data?"];
+
+// two lowercase digits for each byte
+fn hex_encode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => tuple.set_string("", get_hex(&bytes)),
+        Err(err) => vec_table.set_error(&format!("hex_encode: {}", err)),
+    }
+
+    return tuple;
+}
+
+fn get_hex(bytes: &[u8]) -> String {
+    let mut string = String::with_capacity(bytes.len() * 2);
+
+    for byte in bytes.iter() {
+        string += &format!("{:02x}", byte);
+    }
+
+    return string;
+}
+
+const HEX_DECODE_ARGS: [&str; 1] = ["text"];
+
+fn hex_decode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+    let digits: Vec<char> = text.trim().chars().collect();
+    let mut tuple = Tuple::new();
+
+    if !digits.len().is_multiple_of(2) {
+        vec_table.set_error("hex_decode: odd number of digits");
+        return tuple;
+    }
+
+    let mut bytes = Vec::with_capacity(digits.len() / 2);
+
+    for i in (0..(digits.len())).step_by(2) {
+        match (digits[i].to_digit(16), digits[i + 1].to_digit(16)) {
+            (Some(high), Some(low)) => bytes.push((high * 16 + low) as u8),
+            _ => {
+                vec_table.set_error(&format!(
+                    "hex_decode: invalid digits \"{}{}\" at {}",
+                    digits[i],
+                    digits[i + 1],
+                    i
+                ));
+                return tuple;
+            }
+        }
+    }
+
+    tuple.set_tuple("", get_byte_tuple(&bytes));
+
+    return tuple;
+}
+
+const URL_ENCODE_ARGS: [&str; 1] = ["data?"];
+
+// every byte except letters, digits and -_.~ as %XX so it can go anywhere in a url
+fn url_encode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    let bytes = match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            vec_table.set_error(&format!("url_encode: {}", err));
+            return tuple;
+        }
+    };
+
+    let mut string = String::with_capacity(bytes.len());
+
+    for byte in bytes.iter() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                string.push(*byte as char)
+            }
+            _ => string += &format!("%{:02X}", byte),
+        }
+    }
+
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+const URL_DECODE_ARGS: [&str; 1] = ["text"];
+
+// %XX back to its byte and + to a space, the result has to be utf-8
+fn url_decode(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+    let input = text.as_bytes();
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut tuple = Tuple::new();
+
+    let mut i = 0;
+
+    while i < input.len() {
+        match input[i] {
+            b'%' => {
+                let byte = text
+                    .get((i + 1)..(i + 3))
+                    .filter(|digits| digits.bytes().all(|digit| digit.is_ascii_hexdigit()))
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+
+                match byte {
+                    Some(byte) => bytes.push(byte),
+                    None => {
+                        vec_table.set_error(&format!("url_decode: invalid escape at {}", i));
+                        return tuple;
+                    }
+                }
+
+                i += 3;
+            }
+            b'+' => {
+                bytes.push(b' ');
+                i += 1;
+            }
+            byte => {
+                bytes.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    match String::from_utf8(bytes) {
+        Ok(string) => tuple.set_string("", string),
+        Err(err) => vec_table.set_error(&format!("url_decode: {}", err)),
+    }
+
+    return tuple;
+}
+
+const UTF8_BYTES_ARGS: [&str; 1] = ["str"];
+
+fn utf8_bytes(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let string = get_string(table, "str");
+
+    let mut tuple = Tuple::new();
+    tuple.set_tuple("", get_byte_tuple(string.as_bytes()));
+
+    return tuple;
+}
+
+const FROM_UTF8_ARGS: [&str; 1] = ["bytes?"];
+
+fn from_utf8(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    let bytes = match get_bytes(&get_tuple(table, "bytes")) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            vec_table.set_error(&format!("from_utf8: {}", err));
+            return tuple;
+        }
+    };
+
+    match String::from_utf8(bytes) {
+        Ok(string) => tuple.set_string("", string),
+        Err(err) => vec_table.set_error(&format!("from_utf8: {}", err)),
+    }
+
+    return tuple;
+}
+
+// a string gives its utf-8 bytes, a tuple has to be made of integers from 0 to 255
+fn get_bytes(values: &Tuple) -> Result<Vec<u8>, String> {
+    if values.len() == 1 {
+        match values.get(0).kind {
+            Kind::String => {
+                let string = values.table.get_string(values.get(0).pos);
+                return Ok(string.into_bytes());
+            }
+            Kind::Tuple => return get_bytes(&values.table.get_tuple(values.get(0).pos)),
+            _ => {}
+        }
+    }
+
+    let mut bytes = Vec::with_capacity(values.len());
+
+    for i in 0..(values.len()) {
+        let byte = match values.get(i).kind {
+            Kind::BigInt => values.table.get_bigint(values.get(i).pos).to_u8(),
+            _ => None,
+        };
+
+        match byte {
+            Some(byte) => bytes.push(byte),
+            None => return Err(format!("element {} isn't a byte", i)),
+        }
+    }
+
+    return Ok(bytes);
+}
+
+fn get_byte_tuple(bytes: &[u8]) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    for byte in bytes.iter() {
+        tuple.set_bigint("", BigInt::from(*byte));
+    }
+
+    return tuple;
+}
+
+const CRC32_ARGS: [&str; 1] = ["data?"];
+
+fn crc32(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => tuple.set_string("", format!("{:08x}", crc32fast::hash(&bytes))),
+        Err(err) => vec_table.set_error(&format!("crc32: {}", err)),
+    }
+
+    return tuple;
+}
+
+const MD5_ARGS: [&str; 1] = ["data?"];
+
+fn md5(vec_table: &mut VecTable) -> Tuple {
+    return get_digest::<md5::Md5>(vec_table, "md5");
+}
+
+const SHA1_ARGS: [&str; 1] = ["data?"];
+
+fn sha1(vec_table: &mut VecTable) -> Tuple {
+    return get_digest::<sha1::Sha1>(vec_table, "sha1");
+}
+
+const SHA256_ARGS: [&str; 1] = ["data?"];
+
+fn sha256(vec_table: &mut VecTable) -> Tuple {
+    return get_digest::<sha2::Sha256>(vec_table, "sha256");
+}
+
+// the digest of a string or of bytes as hex
+fn get_digest<D: Digest>(vec_table: &mut VecTable, name: &str) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut tuple = Tuple::new();
+
+    match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => tuple.set_string("", get_hex(&D::digest(&bytes))),
+        Err(err) => vec_table.set_error(&format!("{}: {}", name, err)),
+    }
+
+    return tuple;
+}
+
+const HASH_ARGS: [&str; 1] = ["values?"];
+
+// equal values give the same integer, only meant to be used during the same run
+fn hash(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let mut values = get_tuple(table, "values");
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+
+    // a tuple gives the same hash as its elements given one by one
+    if values.len() == 1 && values.get(0).kind == Kind::Tuple {
+        values = values.table.get_tuple(values.get(0).pos);
+    }
+
+    std::hash::Hash::hash(&values, &mut hasher);
+
+    let mut tuple = Tuple::new();
+    tuple.set_bigint("", BigInt::from(std::hash::Hasher::finish(&hasher)));
+
+    return tuple;
+}
+
+const CSV_PARSE_ARGS: [&str; 4] = ["text", "delimiter", "quote", "header"];
+
+// a tuple of rows, with header = true every row is a named tuple
+fn csv_parse(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let text = get_string(table, "text");
+    let mut tuple = Tuple::new();
+
+    let options = match get_csv_options(table) {
+        Ok(options) => options,
+        Err(err) => {
+            vec_table.set_error(&format!("csv_parse: {}", err));
+            return tuple;
+        }
+    };
+
+    let mut reader = std::io::Cursor::new(text.as_bytes());
+    let mut names = None;
+    let mut rows = Tuple::new();
+
+    loop {
+        let fields = match read_record(&mut reader, &options) {
+            Ok(Some(fields)) => fields,
+            Ok(None) => break,
+            Err(err) => {
+                vec_table.set_error(&format!("csv_parse: {}", err));
+                return tuple;
+            }
+        };
+
+        if options.header && names.is_none() {
+            names = Some(fields);
+        } else {
+            rows.set_tuple("", get_row(fields, &names));
+        }
+    }
+
+    tuple.set_tuple("", rows);
+
+    return tuple;
+}
+
+const CSV_STRINGIFY_ARGS: [&str; 4] = ["rows", "delimiter", "quote", "header"];
+
+// one line for each row, header = true writes the names of the first row before
+fn csv_stringify(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let rows = get_tuple(table, "rows");
+    let mut tuple = Tuple::new();
+
+    let options = match get_csv_options(table) {
+        Ok(options) => options,
+        Err(err) => {
+            vec_table.set_error(&format!("csv_stringify: {}", err));
+            return tuple;
+        }
+    };
+
+    let delimiter = options.delimiter.to_string();
+    let mut string = String::new();
+
+    for i in 0..(rows.len()) {
+        if rows.get(i).kind != Kind::Tuple {
+            vec_table.set_error(&format!("csv_stringify: row {} isn't a tuple", i));
+            return tuple;
+        }
+
+        let row = rows.table.get_tuple(rows.get(i).pos);
+
+        if options.header && i == 0 {
+            let names: Vec<String> = (0..(row.len()))
+                .map(|n| get_field(get_real_name(row.get_name(n)), &options))
+                .collect();
+
+            string += &names.join(&delimiter);
+            string.push('\n');
+        }
+
+        let mut fields = Vec::with_capacity(row.len());
+
+        for n in 0..(row.len()) {
+            let var = row.get(n);
+
+            let value = match var.kind {
+                Kind::Null => String::new(),
+                Kind::String | Kind::Number | Kind::BigInt | Kind::Bool => {
+                    var.get_string(row.get_name(n), &row.table).unwrap()
+                }
+                _ => {
+                    vec_table.set_error(&format!(
+                        "csv_stringify: a field can't be a {}",
+                        var.kind.get_str()
+                    ));
+                    return tuple;
+                }
+            };
+
+            fields.push(get_field(&value, &options));
+        }
+
+        string += &fields.join(&delimiter);
+        string.push('\n');
+    }
+
+    tuple.set_string("", string);
+
+    return tuple;
+}
+
+const CSV_ROWS_ARGS: [&str; 4] = ["path", "delimiter", "quote", "header"];
+
+// an iterator over the rows of a file so it never has to be read all at once
+fn csv_rows(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let path = get_string(table, "path");
+    let mut tuple = Tuple::new();
+
+    let options = match get_csv_options(table) {
+        Ok(options) => options,
+        Err(err) => {
+            vec_table.set_error(&format!("csv_rows: {}", err));
+            return tuple;
+        }
+    };
+
+    match std::fs::File::open(&path) {
+        Ok(file) => {
+            let reader = Box::new(CsvReader::new(file, options));
+            tuple.set_iterator("", vec_table.iterators.add(Iter::Csv(reader)));
+        }
+        Err(err) => vec_table.set_error(&format!("can't read {}: {}", path, err)),
+    }
+
+    return tuple;
+}
+
+// "," and "\"" unless they are given, the header is off by default
+fn get_csv_options(table: &mut Table) -> Result<CsvOptions, String> {
+    let mut options = CsvOptions::new();
+
+    if table.get("delimiter").kind != Kind::Null {
+        options.delimiter = get_csv_char(table, "delimiter")?;
+    }
+
+    if table.get("quote").kind != Kind::Null {
+        options.quote = get_csv_char(table, "quote")?;
+    }
+
+    if table.get("header").kind != Kind::Null {
+        options.header = table.get("header").get_bool("header", table)?;
+    }
+
+    if options.delimiter == options.quote {
+        return Err(String::from(
+            "the delimiter and the quote can't be the same",
+        ));
+    }
+
+    return Ok(options);
+}
+
+fn get_csv_char(table: &mut Table, entry: &str) -> Result<char, String> {
+    let string = get_string(table, entry);
+    let mut chars = string.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) if ch != '\n' && ch != '\r' => Ok(ch),
+        _ => Err(format!("the {} has to be a single character", entry)),
+    }
+}
+
+const TCP_CONNECT_ARGS: [&str; 2] = ["host", "port"];
+
+fn tcp_connect(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let host = get_string(table, "host");
+    let mut tuple = Tuple::new();
+
+    let port = match get_bigint(table, "port").to_u16() {
+        Some(port) => port,
+        None => {
+            vec_table.set_error("tcp_connect: the port goes from 0 to 65535");
+            return tuple;
+        }
+    };
+
+    match TcpStream::connect((host.as_str(), port)) {
+        Ok(stream) => tuple.set_handle("", vec_table.handles.add(Handle::Tcp(stream))),
+        Err(err) => vec_table.set_error(&format!("can't connect to {}:{}: {}", host, port, err)),
+    }
+
+    return tuple;
+}
+
+const TCP_LISTEN_ARGS: [&str; 1] = ["addr"];
+
+// "127.0.0.1:0" lets the system pick the port, local_addr() tells which one
+fn tcp_listen(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let addr = get_string(table, "addr");
+    let mut tuple = Tuple::new();
+
+    match TcpListener::bind(&addr) {
+        Ok(listener) => tuple.set_handle("", vec_table.handles.add(Handle::TcpListener(listener))),
+        Err(err) => vec_table.set_error(&format!("can't listen on {}: {}", addr, err)),
+    }
+
+    return tuple;
+}
+
+const ACCEPT_ARGS: [&str; 2] = ["listener", "timeout"];
+
+// the next connection, null once the timeout in seconds is over
+fn accept(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "listener");
+    let timeout = get_timeout(table);
+    let mut tuple = Tuple::new();
+
+    let listener = match handle.and_then(|pos| vec_table.handles.get(pos)) {
+        Some(Handle::TcpListener(listener)) => listener,
+        _ => {
+            vec_table.set_error("accept: the handle isn't an open tcp listener");
+            return tuple;
+        }
+    };
+
+    match accept_stream(listener, timeout) {
+        Ok(Some(stream)) => tuple.set_handle("", vec_table.handles.add(Handle::Tcp(stream))),
+        Ok(None) => tuple.set_null(""),
+        Err(err) => vec_table.set_error(&format!("accept: {}", err)),
+    }
+
+    return tuple;
+}
+
+// None once the timeout is over without a connection
+// a connection that failed before it was accepted is skipped, other errors are given back
+fn accept_stream(
+    listener: &TcpListener,
+    timeout: Option<std::time::Duration>,
+) -> std::io::Result<Option<TcpStream>> {
+    let start = std::time::Instant::now();
+
+    // without blocking so the timeout can be checked between two tries
+    listener.set_nonblocking(timeout.is_some())?;
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                stream.set_nonblocking(false)?;
+                return Ok(Some(stream));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+                    return Ok(None);
+                }
+
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+            Err(err)
+                if matches!(
+                    err.kind(),
+                    std::io::ErrorKind::ConnectionAborted
+                        | std::io::ErrorKind::ConnectionReset
+                        | std::io::ErrorKind::Interrupted
+                ) => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+const SEND_ARGS: [&str; 2] = ["conn", "data?"];
+
+// a string or bytes, gives back how many bytes were sent
+fn send(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "conn");
+    let mut tuple = Tuple::new();
+
+    let bytes = match get_bytes(&get_tuple(table, "data")) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            vec_table.set_error(&format!("send: {}", err));
+            return tuple;
+        }
+    };
+
+    let result = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::Tcp(stream)) => stream.write_all(&bytes),
+        _ => {
+            vec_table.set_error("send: the handle isn't an open tcp connection");
+            return tuple;
+        }
+    };
+
+    match result {
+        Ok(()) => tuple.set_bigint("", BigInt::from(bytes.len())),
+        Err(err) => vec_table.set_error(&format!("send: {}", err)),
+    }
+
+    return tuple;
+}
+
+const RECV_ARGS: [&str; 3] = ["conn", "n", "timeout"];
+
+// up to n bytes as soon as some are there, () once the other side closed and null after the timeout
+fn recv(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "conn");
+    let timeout = get_timeout(table);
+    let mut tuple = Tuple::new();
+
+    let mut buffer = match get_buffer(table, "n") {
+        Ok(buffer) => buffer,
+        Err(err) => {
+            vec_table.set_error(&format!("recv: {}", err));
+            return tuple;
+        }
+    };
+
+    let received = match handle.and_then(|pos| vec_table.handles.get_mut(pos)) {
+        Some(Handle::Tcp(stream)) => match stream.set_read_timeout(timeout) {
+            Ok(()) => std::io::Read::read(stream, &mut buffer),
+            Err(err) => Err(err),
+        },
+        _ => {
+            vec_table.set_error("recv: the handle isn't an open tcp connection");
+            return tuple;
+        }
+    };
+
+    match received {
+        Ok(len) => tuple.set_tuple("", get_byte_tuple(&buffer[..len])),
+        Err(err) if is_timeout(&err) => tuple.set_null(""),
+        Err(err) => vec_table.set_error(&format!("recv: {}", err)),
+    }
+
+    return tuple;
+}
+
+const UDP_BIND_ARGS: [&str; 1] = ["addr"];
+
+fn udp_bind(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let addr = get_string(table, "addr");
+    let mut tuple = Tuple::new();
+
+    match UdpSocket::bind(&addr) {
+        Ok(socket) => tuple.set_handle("", vec_table.handles.add(Handle::Udp(socket))),
+        Err(err) => vec_table.set_error(&format!("can't bind {}: {}", addr, err)),
+    }
+
+    return tuple;
+}
+
+const UDP_SEND_TO_ARGS: [&str; 3] = ["socket", "data", "addr"];
+
+// one datagram, gives back how many bytes were sent
+fn udp_send_to(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "socket");
+    let addr = get_string(table, "addr");
+    let mut tuple = Tuple::new();
+
+    let bytes = match get_bytes(&get_element(table, "data")) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            vec_table.set_error(&format!("udp_send_to: {}", err));
+            return tuple;
+        }
+    };
+
+    let result = match handle.and_then(|pos| vec_table.handles.get(pos)) {
+        Some(Handle::Udp(socket)) => socket.send_to(&bytes, &addr),
+        _ => {
+            vec_table.set_error("udp_send_to: the handle isn't an open udp socket");
+            return tuple;
+        }
+    };
+
+    match result {
+        Ok(len) => tuple.set_bigint("", BigInt::from(len)),
+        Err(err) => vec_table.set_error(&format!("can't send to {}: {}", addr, err)),
+    }
+
+    return tuple;
+}
+
+const UDP_RECV_FROM_ARGS: [&str; 3] = ["socket", "n", "timeout"];
+
+// (data, addr) for the next datagram, null after the timeout
+fn udp_recv_from(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "socket");
+    let timeout = get_timeout(table);
+    let mut tuple = Tuple::new();
+
+    let mut buffer = match get_buffer(table, "n") {
+        Ok(buffer) => buffer,
+        Err(err) => {
+            vec_table.set_error(&format!("udp_recv_from: {}", err));
+            return tuple;
+        }
+    };
+
+    let received = match handle.and_then(|pos| vec_table.handles.get(pos)) {
+        Some(Handle::Udp(socket)) => match socket.set_read_timeout(timeout) {
+            Ok(()) => socket.recv_from(&mut buffer),
+            Err(err) => Err(err),
+        },
+        _ => {
+            vec_table.set_error("udp_recv_from: the handle isn't an open udp socket");
+            return tuple;
+        }
+    };
+
+    match received {
+        Ok((len, addr)) => {
+            let mut info = Tuple::new();
+            info.set_tuple("data", get_byte_tuple(&buffer[..len]));
+            info.set_string("addr", addr.to_string());
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) if is_timeout(&err) => tuple.set_null(""),
+        Err(err) => vec_table.set_error(&format!("udp_recv_from: {}", err)),
+    }
+
+    return tuple;
+}
+
+const LOCAL_ADDR_ARGS: [&str; 1] = ["handle"];
+
+// (host, port) of this side of a socket
+fn local_addr(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let handle = get_handle(table, "handle");
+    let mut tuple = Tuple::new();
+
+    let addr = match handle.and_then(|pos| vec_table.handles.get(pos)) {
+        Some(Handle::Tcp(stream)) => stream.local_addr(),
+        Some(Handle::TcpListener(listener)) => listener.local_addr(),
+        Some(Handle::Udp(socket)) => socket.local_addr(),
+        _ => {
+            vec_table.set_error("local_addr: the handle isn't an open socket");
+            return tuple;
+        }
+    };
+
+    match addr {
+        Ok(addr) => {
+            let mut info = Tuple::new();
+            info.set_string("host", addr.ip().to_string());
+            info.set_bigint("port", BigInt::from(addr.port()));
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) => vec_table.set_error(&format!("local_addr: {}", err)),
+    }
+
+    return tuple;
+}
+
+// None waits as long as it has to
+fn get_timeout(table: &mut Table) -> Option<std::time::Duration> {
+    match table.get("timeout").kind {
+        Kind::Null => None,
+        _ => Some(std::time::Duration::from_secs_f64(
+            get_number(table, "timeout").max(0.001),
+        )),
+    }
+}
+
+// room for n bytes, 65536 without n which is enough for any datagram
+fn get_buffer(table: &mut Table, entry: &str) -> Result<Vec<u8>, String> {
+    if table.get(entry).kind == Kind::Null {
+        return Ok(vec![0; 65536]);
+    }
+
+    match get_bigint(table, entry).to_usize() {
+        Some(len) if len > 0 => Ok(vec![0; len]),
+        _ => Err(String::from("n has to be a positive integer")),
+    }
+}
+
+fn is_timeout(err: &std::io::Error) -> bool {
+    matches!(
+        err.kind(),
+        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+    )
+}
+
+const HTTP_GET_ARGS: [&str; 2] = ["url", "headers"];
+
+fn http_get(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let url = get_string(table, "url");
+
+    let headers = match get_http_headers(table, "headers") {
+        Ok(headers) => headers,
+        Err(err) => {
+            vec_table.set_error(&format!("http_get: {}", err));
+            return Tuple::new();
+        }
+    };
+
+    return send_http(vec_table, "GET", &url, &[], &headers);
+}
+
+const HTTP_REQUEST_ARGS: [&str; 4] = ["method", "url", "body", "headers"];
+
+// (status, headers, body) the names of the headers are in lowercase and the body is a string
+fn http_request(vec_table: &mut VecTable) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let method = get_string(table, "method").to_uppercase();
+    let url = get_string(table, "url");
+
+    let body = match table.get("body").kind {
+        Kind::Null => Ok(Vec::new()),
+        _ => get_bytes(&get_element(table, "body")),
+    };
+
+    let request = body.and_then(|body| Ok((body, get_http_headers(table, "headers")?)));
+
+    match request {
+        Ok((body, headers)) => return send_http(vec_table, &method, &url, &body, &headers),
+        Err(err) => {
+            vec_table.set_error(&format!("http_request: {}", err));
+            return Tuple::new();
+        }
+    }
+}
+
+fn send_http(
+    vec_table: &mut VecTable,
+    method: &str,
+    url: &str,
+    body: &[u8],
+    headers: &[(String, String)],
+) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    match http::request(method, url, body, headers) {
+        Ok(response) => {
+            let mut info = Tuple::new();
+            info.set_bigint("status", BigInt::from(response.status));
+            info.set_tuple("headers", get_header_tuple(&response.headers));
+            info.set_string("body", String::from_utf8_lossy(&response.body).to_string());
+
+            tuple.set_tuple("", info);
+        }
+        Err(err) => vec_table.set_error(&format!("{} {}: {}", method, url, err)),
+    }
+
+    return tuple;
+}
+
+const HTTP_SERVE_ARGS: [&str; 4] = ["addr", "handler", "requests", "timeout"];
+
+// handler(request) is called for each request with (method, path, query, headers, body)
+// it returns a body, (status, body), (status, headers, body) or null for 204
+// it only returns after the given amount of requests or once no connection came for timeout seconds
+fn http_serve(vec_table: &mut VecTable, process: &Process) -> Tuple {
+    let table = vec_table.get_level(vec_table.len() - 1);
+    let addr = get_string(table, "addr");
+    let timeout = get_timeout(table);
+
+    let limit = match table.get("requests").kind {
+        Kind::Null => None,
+        _ => Some(get_bigint(table, "requests")),
+    };
+
+    let handler = match get_function(table, "handler") {
+        Some(handler) => handler,
+        None => {
+            vec_table.set_error("the handler isn't a function");
+            return Tuple::new();
+        }
+    };
+
+    let listener = match TcpListener::bind(&addr) {
+        Ok(listener) => listener,
+        Err(err) => {
+            vec_table.set_error(&format!("can't listen on {}: {}", addr, err));
+            return Tuple::new();
+        }
+    };
+
+    let mut served = BigInt::zero();
+
+    while limit.as_ref().is_none_or(|limit| served < *limit) {
+        let mut stream = match accept_stream(&listener, timeout) {
+            Ok(Some(stream)) => stream,
+            Ok(None) => break,
+            Err(err) => {
+                vec_table.set_error(&format!("http_serve: {}", err));
+                return Tuple::new();
+            }
+        };
+
+        // a client that stops reading or writing doesn't hold the server
+        let timeouts = stream
+            .set_read_timeout(Some(http::READ_TIMEOUT))
+            .and_then(|_| stream.set_write_timeout(Some(http::READ_TIMEOUT)));
+
+        if timeouts.is_err() {
+            continue;
+        }
+
+        let request = match http::read_request(&stream) {
+            Ok(Some(request)) => request,
+            Ok(None) => continue,
+            Err((status, err)) => {
+                let _ = http::write_response(&mut stream, &get_error_response(status, &err));
+                continue;
+            }
+        };
+
+        served += 1;
+
+        let mut info = Tuple::new();
+        info.set_string("method", request.method);
+        info.set_string("path", request.path);
+        info.set_string("query", request.query);
+        info.set_tuple("headers", get_header_tuple(&request.headers));
+        info.set_string("body", String::from_utf8_lossy(&request.body).to_string());
+
+        let mut arguments = Tuple::new();
+        arguments.set_tuple("", info);
+
+        let result = handler.run(&arguments, process, vec_table);
+
+        if vec_table.error.is_some() {
+            let response = get_error_response(500, "the handler failed");
+            let _ = http::write_response(&mut stream, &response);
+
+            return Tuple::new();
+        }
+
+        match get_http_response(&result) {
+            Ok(response) => {
+                let _ = http::write_response(&mut stream, &response);
+            }
+            Err(err) => {
+                let _ = http::write_response(&mut stream, &get_error_response(500, &err));
+
+                vec_table.set_error(&format!("http_serve: {}", err));
+                return Tuple::new();
+            }
+        }
+
+        // exit() stops the server once the request that called it is answered
+        if vec_table.exit.is_some() {
+            return Tuple::new();
+        }
+    }
+
+    return Tuple::new();
+}
+
+fn get_error_response(status: u16, message: &str) -> http::Response {
+    return http::Response {
+        status: status,
+        headers: Vec::new(),
+        body: message.as_bytes().to_vec(),
+    };
+}
+
+// what the handler of http_serve() returned, a tuple named like the result of http_request() works too
+fn get_http_response(result: &Tuple) -> Result<http::Response, String> {
+    let mut values = result.clone();
+
+    if values.len() == 1 && values.get(0).kind == Kind::Tuple {
+        values = values.table.get_tuple(values.get(0).pos);
+    }
+
+    let mut response = http::Response {
+        status: 200,
+        headers: Vec::new(),
+        body: Vec::new(),
+    };
+
+    let find =
+        |name: &str| (0..(values.len())).find(|&i| get_real_name(values.get_name(i)) == name);
+
+    // where the status, the headers and the body are in what was returned
+    let (status, headers, body) = if find("status").is_some() {
+        (find("status"), find("headers"), find("body"))
+    } else {
+        match values.len() {
+            0 => (None, None, None),
+            1 if values.get(0).kind == Kind::BigInt => (Some(0), None, None),
+            1 => (None, None, Some(0)),
+            2 => (Some(0), None, Some(1)),
+            3 => (Some(0), Some(1), Some(2)),
+            _ => return Err(String::from("a response is (status, headers, body)")),
+        }
+    };
+
+    if let Some(i) = status {
+        response.status = match values.get(i).get_bigint(values.get_name(i), &values.table) {
+            Ok(status) => match status.to_u16() {
+                Some(status) if (100..1000).contains(&status) => status,
+                _ => return Err(format!("invalid status {}", status)),
+            },
+            Err(_) => return Err(String::from("the status has to be an integer")),
+        };
+    }
+
+    if let Some(i) = headers {
+        match values.get(i).kind {
+            Kind::Null => {}
+            Kind::Tuple => {
+                response.headers = get_header_list(&values.table.get_tuple(values.get(i).pos))?
+            }
+            _ => return Err(String::from("the headers are (name, value) pairs")),
+        }
+
+        for (name, value) in response.headers.iter() {
+            http::check_header(name, value)?;
+        }
+    }
+
+    match body {
+        Some(i) if values.get(i).kind != Kind::Null => {
+            let mut element = Tuple::new();
+            element.push(values.get(i), "", &values.table);
+
+            response.body = get_bytes(&element)?;
+        }
+        _ if status.is_none() => response.status = 204,
+        _ => {}
+    }
+
+    return Ok(response);
+}
+
+// (name, value) pairs or values named after their header
+fn get_http_headers(table: &mut Table, entry: &str) -> Result<Vec<(String, String)>, String> {
+    match table.get(entry).kind {
+        Kind::Null => Ok(Vec::new()),
+        Kind::Tuple => get_header_list(&get_tuple(table, entry)),
+        _ => Err(String::from("the headers are (name, value) pairs")),
+    }
+}
+
+fn get_header_list(headers: &Tuple) -> Result<Vec<(String, String)>, String> {
+    let mut list = Vec::new();
+
+    // a single pair isn't in a tuple of its own
+    let single = headers.len() == 2
+        && (0..2).all(|i| {
+            headers.get(i).kind == Kind::String && get_real_name(headers.get_name(i)).is_empty()
+        });
+
+    if single {
+        list.push((
+            headers.table.get_string(headers.get(0).pos),
+            headers.table.get_string(headers.get(1).pos),
+        ));
+
+        return Ok(list);
+    }
+
+    for i in 0..(headers.len()) {
+        let var = headers.get(i);
+        let name = get_real_name(headers.get_name(i));
+
+        if var.kind == Kind::Tuple {
+            let pair = headers.table.get_tuple(var.pos);
+
+            if pair.len() == 2 {
+                list.push((
+                    pair.get(0).get_string(pair.get_name(0), &pair.table)?,
+                    pair.get(1).get_string(pair.get_name(1), &pair.table)?,
+                ));
+                continue;
+            }
+        } else if !name.is_empty() {
+            list.push((
+                name.to_string(),
+                var.get_string(headers.get_name(i), &headers.table)?,
+            ));
+            continue;
+        }
+
+        return Err(String::from("the headers are (name, value) pairs"));
+    }
+
+    return Ok(list);
+}
+
+fn get_header_tuple(headers: &[(String, String)]) -> Tuple {
+    let mut tuple = Tuple::new();
+
+    for (name, value) in headers.iter() {
+        tuple.set_string(name, value.clone());
+    }
+
+    return tuple;
+}
+
+#[allow(dead_code)]
+fn get_number(table: &mut Table, entry: &str) -> f64 {
+    match table.get(entry).get_number(entry, table) {
+        Ok(num) => num,
+        Err(_) => 0.0,
+    }
+}
+
+#[allow(dead_code)]
+fn get_string(table: &mut Table, entry: &str) -> String {
+    match table.get(entry).get_string(entry, table) {
+        Ok(string) => string,
+        Err(_) => String::new(),
+    }
+}
+
+#[allow(dead_code)]
+fn get_bigint(table: &mut Table, entry: &str) -> BigInt {
+    match table.get(entry).get_bigint(entry, table) {
+        Ok(bigint) => bigint,
+        Err(_) => BigInt::zero(),
+    }
+}
+
+#[allow(dead_code)]
+fn get_tuple(table: &mut Table, entry: &str) -> Tuple {
+    match table.get(entry).get_tuple(entry, table) {
+        Ok(tuple) => tuple,
+        Err(_) => Tuple::new(),
+    }
+}
+
+#[allow(dead_code)]
+fn get_handle(table: &mut Table, entry: &str) -> Option<usize> {
+    table.get(entry).get_handle(entry, table).ok()
+}
+
+fn get_function(table: &mut Table, entry: &str) -> Option<Function> {
+    table.get(entry).get_function(entry, table).ok()
+}
+
+#[allow(dead_code)]
+fn get_iterator(table: &mut Table, entry: &str) -> Option<usize> {
+    table.get(entry).get_iterator(entry, table).ok()
 }
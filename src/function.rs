@@ -1,4 +1,5 @@
 use crate::default_fn::*;
+use crate::iterator::*;
 use crate::kind::*;
 use crate::process::*;
 use crate::table::*;
@@ -12,6 +13,7 @@ pub const ENUMERATE_ARGS: &str = "?"; // if the name of the last argument of a f
 
 pub struct Function {
     pub default_fn: bool,
+    pub generator: bool, // has a yield, so calling it gives an iterator instead of running it
     pub pos: usize,
     pub arguments: Tuple,
     pub table: Table,
@@ -21,6 +23,7 @@ impl Function {
     pub fn new(default_fn: bool, pos: usize, arguments: Tuple) -> Self {
         Self {
             default_fn: default_fn,
+            generator: false,
             pos: pos,
             arguments: arguments,
             table: Table::new(),
@@ -53,8 +56,16 @@ impl Function {
         for i in 0..len {
             let var = arguments.get(i);
 
-            if i < self.arguments.len() - 1 || !enumerate {
-                let name = get_real_name(self.arguments.get_name(i));
+            // name = value goes to the argument with that name wherever it is
+            let keyword = get_real_name(arguments.get_name(i))
+                .strip_prefix(KEYWORD_PREFIX)
+                .filter(|keyword| {
+                    (0..(self.arguments.len()))
+                        .any(|n| get_real_name(self.arguments.get_name(n)) == *keyword)
+                });
+
+            if keyword.is_some() || i < self.arguments.len() - 1 || !enumerate {
+                let name = keyword.unwrap_or_else(|| get_real_name(self.arguments.get_name(i)));
 
                 match var.kind {
                     Kind::String => {
@@ -72,11 +83,22 @@ impl Function {
                     Kind::Tuple => {
                         table.set_tuple(name, arguments.table.vec_tuple[var.pos].clone());
                     }
+                    Kind::Iterator => {
+                        table.set_iterator(name, arguments.table.vec_iterator[var.pos]);
+                    }
+                    Kind::Range => {
+                        table.set_range(name, arguments.table.vec_range[var.pos].clone());
+                    }
+                    Kind::Handle => {
+                        table.set_handle(name, arguments.table.vec_handle[var.pos]);
+                    }
                     Kind::Operator => {}
                     Kind::Null => {
                         table.set_null(name, true);
                     }
-                    Kind::Function => {}
+                    Kind::Function => {
+                        table.set_function(name, arguments.table.vec_function[var.pos].clone());
+                    }
                 }
             } else if enumerate {
                 let mut name = get_real_name(self.arguments.get_name(self.arguments.len() - 1));
@@ -94,10 +116,24 @@ impl Function {
             }
         }
 
+        if self.generator {
+            let generator = Generator::new(process.clone(), self.pos, vec_table.remove_level());
+
+            let pos = vec_table
+                .iterators
+                .add(Iter::Generator(Box::new(generator)));
+
+            let mut tuple = Tuple::new();
+            tuple.set_iterator("", pos);
+
+            return tuple;
+        }
+
         let val;
 
         if self.default_fn {
-            val = DEFAULTS_FUNCTIONS[self.pos].run(vec_table);
+            val = DEFAULTS_FUNCTIONS[self.pos].run(vec_table, process);
+            vec_table.remove_level();
         } else {
             eprintln!("");
 
@@ -105,9 +141,19 @@ impl Function {
 
             eprintln!("\nlevel: {}", vec_table.len() - 2);
             eprintln!("\n{}\t: {}\t: {}\n", "name", "kind", "value");
-        };
 
-        vec_table.remove_level();
+            // the iterators of its variables go with the function, except the ones it returned
+            let table = vec_table.remove_level();
+
+            let mut dropped = Vec::new();
+            get_held_iterators(&table, &mut dropped);
+
+            let mut returned = Vec::new();
+            get_held_iterators(&val.table, &mut returned);
+
+            dropped.retain(|pos| !returned.contains(pos));
+            vec_table.drop_iterators(dropped);
+        };
 
         return val;
     }
@@ -117,6 +163,7 @@ impl Clone for Function {
     fn clone(&self) -> Self {
         Self {
             default_fn: self.default_fn,
+            generator: self.generator,
             pos: self.pos,
             arguments: self.arguments.clone(),
             table: self.table.clone()
@@ -0,0 +1,47 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use websocket::sync::Client;
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+// something kept open between calls, scripts only see its position in VecTable.handles
+pub enum Handle {
+    Reader(BufReader<File>),
+    Writer(File),
+    WebSocket(Client<TcpStream>),
+    Tcp(TcpStream),
+    TcpListener(TcpListener),
+    Udp(UdpSocket),
+    Closed,
+}
+
+impl Clone for Handle {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Reader(reader) => match reader.get_ref().try_clone() {
+                Ok(file) => Self::Reader(BufReader::new(file)),
+                Err(_) => Self::Closed,
+            },
+            Self::Writer(file) => match file.try_clone() {
+                Ok(file) => Self::Writer(file),
+                Err(_) => Self::Closed,
+            },
+            Self::WebSocket(_) => Self::Closed, // what it already read can't be shared
+            Self::Tcp(stream) => match stream.try_clone() {
+                Ok(stream) => Self::Tcp(stream),
+                Err(_) => Self::Closed,
+            },
+            Self::TcpListener(listener) => match listener.try_clone() {
+                Ok(listener) => Self::TcpListener(listener),
+                Err(_) => Self::Closed,
+            },
+            Self::Udp(socket) => match socket.try_clone() {
+                Ok(socket) => Self::Udp(socket),
+                Err(_) => Self::Closed,
+            },
+            Self::Closed => Self::Closed,
+        }
+    }
+}
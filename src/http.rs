@@ -0,0 +1,427 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+pub const MAX_BODY: u64 = 16 * 1024 * 1024; // a bigger body is refused instead of filling the memory
+pub const MAX_HEAD: u64 = 64 * 1024; // the request or status line and the headers together
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10); // the other side of a connection that stays silent is dropped after it
+
+// the status to answer with when a request can't be read and why
+type HttpError = (u16, String);
+
+// what http_request() gives back and what the handler of http_serve() returns
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+// only plain http, the host and port to connect to and the path to ask for
+fn parse_url(url: &str) -> Result<(String, u16, String), String> {
+    let rest = match url.strip_prefix("http://") {
+        Some(rest) => rest,
+        None if url.starts_with("https://") => return Err(String::from("https isn't supported")),
+        None => return Err(format!("{} isn't an http url", url)),
+    };
+
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, "/"),
+    };
+
+    let (host, port) = match authority.rfind(':') {
+        Some(pos) if !authority.ends_with(']') => match authority[(pos + 1)..].parse() {
+            Ok(port) => (&authority[..pos], port),
+            Err(_) => return Err(format!("invalid port in {}", url)),
+        },
+        _ => (authority, 80),
+    };
+
+    // a space or a line break would end the request line early
+    if url.chars().any(|ch| ch.is_whitespace() || ch.is_control()) {
+        return Err(format!("invalid character in {:?}", url));
+    }
+
+    if host.is_empty() {
+        return Err(format!("no host in {}", url));
+    }
+
+    return Ok((
+        host.trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string(),
+        port,
+        path.to_string(),
+    ));
+}
+
+pub fn request(
+    method: &str,
+    url: &str,
+    body: &[u8],
+    headers: &[(String, String)],
+) -> Result<Response, String> {
+    let (host, port, path) = parse_url(url)?;
+
+    if !is_token(method) {
+        return Err(format!("invalid method {:?}", method));
+    }
+
+    for (name, value) in headers.iter() {
+        check_header(name, value)?;
+    }
+
+    let mut stream = connect(&host, port)
+        .map_err(|err| format!("can't connect to {}:{}: {}", host, port, err))?;
+
+    let mut head = format!("{} {} HTTP/1.1\r\n", method, path);
+
+    if !has_header(headers, "host") {
+        if port == 80 {
+            head += &format!("Host: {}\r\n", host);
+        } else {
+            head += &format!("Host: {}:{}\r\n", host, port);
+        }
+    }
+
+    // one request for each connection so the end of the response is always known
+    head += "Connection: close\r\n";
+
+    if !body.is_empty() || method == "POST" || method == "PUT" || method == "PATCH" {
+        head += &format!("Content-Length: {}\r\n", body.len());
+    }
+
+    for (name, value) in headers.iter() {
+        head += &format!("{}: {}\r\n", name, value);
+    }
+
+    head += "\r\n";
+
+    stream
+        .write_all(head.as_bytes())
+        .and_then(|_| stream.write_all(body))
+        .map_err(|err| err.to_string())?;
+
+    let mut reader = BufReader::new(stream);
+    let mut left = MAX_HEAD;
+    let status_line = read_line(&mut reader, &mut left).map_err(|(_, err)| err)?;
+    let mut parts = status_line.splitn(3, ' ');
+
+    let status = match (parts.next(), parts.next()) {
+        (Some(version), Some(status)) if version.starts_with("HTTP/") => status
+            .parse()
+            .map_err(|_| format!("invalid status line \"{}\"", status_line))?,
+        _ => return Err(format!("invalid status line \"{}\"", status_line)),
+    };
+
+    let headers = read_headers(&mut reader, &mut left).map_err(|(_, err)| err)?;
+
+    let body = if method == "HEAD" || status == 204 || status == 304 {
+        Vec::new()
+    } else {
+        read_body(&mut reader, &headers, true).map_err(|(_, err)| err)?
+    };
+
+    return Ok(Response {
+        status: status,
+        headers: headers,
+        body: body,
+    });
+}
+
+// each address of the host is tried, a server that doesn't answer can't hold it forever
+fn connect(host: &str, port: u16) -> std::io::Result<TcpStream> {
+    let mut last_err = None;
+
+    for addr in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, READ_TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(READ_TIMEOUT))?;
+                stream.set_write_timeout(Some(READ_TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    return Err(last_err.unwrap_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "no address for the host")
+    }));
+}
+
+// a name or a value with a line break in it would add headers of its own
+pub fn check_header(name: &str, value: &str) -> Result<(), String> {
+    if !is_token(name) {
+        return Err(format!("invalid header name {:?}", name));
+    }
+
+    if value.chars().any(|ch| ch.is_control() && ch != '\t') {
+        return Err(format!("invalid value for the header {}", name));
+    }
+
+    return Ok(());
+}
+
+// what a method or the name of a header is made of
+fn is_token(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
+
+// None when the connection closed before sending anything
+pub fn read_request(stream: &TcpStream) -> Result<Option<Request>, HttpError> {
+    let mut reader = BufReader::new(stream);
+    let mut left = MAX_HEAD;
+    let request_line = read_line(&mut reader, &mut left)?;
+
+    if request_line.is_empty() {
+        return Ok(None);
+    }
+
+    let parts: Vec<&str> = request_line.split(' ').collect();
+
+    if parts.len() != 3 || !parts[2].starts_with("HTTP/") {
+        return Err((400, format!("invalid request line \"{}\"", request_line)));
+    }
+
+    let (path, query) = match parts[1].find('?') {
+        Some(pos) => (&parts[1][..pos], &parts[1][(pos + 1)..]),
+        None => (parts[1], ""),
+    };
+
+    let headers = read_headers(&mut reader, &mut left)?;
+    let body = read_body(&mut reader, &headers, false)?;
+
+    return Ok(Some(Request {
+        method: parts[0].to_string(),
+        path: path.to_string(),
+        query: query.to_string(),
+        headers: headers,
+        body: body,
+    }));
+}
+
+pub fn write_response(stream: &mut TcpStream, response: &Response) -> Result<(), String> {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        get_reason(response.status)
+    );
+
+    if !has_header(&response.headers, "content-type") {
+        head += "Content-Type: text/plain; charset=utf-8\r\n";
+    }
+
+    head += &format!(
+        "Content-Length: {}\r\nConnection: close\r\n",
+        response.body.len()
+    );
+
+    for (name, value) in response.headers.iter() {
+        let lower = name.to_lowercase();
+
+        // they are already written the way they have to be
+        if lower != "content-length" && lower != "connection" {
+            head += &format!("{}: {}\r\n", name, value);
+        }
+    }
+
+    head += "\r\n";
+
+    return stream
+        .write_all(head.as_bytes())
+        .and_then(|_| stream.write_all(&response.body))
+        .and_then(|_| stream.flush())
+        .map_err(|err| err.to_string());
+}
+
+fn has_header(headers: &[(String, String)], name: &str) -> bool {
+    headers
+        .iter()
+        .any(|(key, _)| key.eq_ignore_ascii_case(name))
+}
+
+fn get_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+// without the \r\n, empty at the end of the stream
+// at most left bytes are read so a line without an end can't fill the memory
+fn read_line<R: BufRead>(reader: &mut R, left: &mut u64) -> Result<String, HttpError> {
+    let mut line = Vec::new();
+
+    let read = reader
+        .take(*left)
+        .read_until(b'\n', &mut line)
+        .map_err(get_io_error)?;
+
+    *left -= read as u64;
+
+    if *left == 0 && !line.ends_with(b"\n") {
+        return Err((431, format!("the head is bigger than {} bytes", MAX_HEAD)));
+    }
+
+    let line = String::from_utf8(line).map_err(|_| (400, String::from("the head isn't utf-8")))?;
+
+    return Ok(line.trim_end_matches(&['\r', '\n'][..]).to_string());
+}
+
+// the names are in lowercase since they don't depend on the case
+fn read_headers<R: BufRead>(
+    reader: &mut R,
+    left: &mut u64,
+) -> Result<Vec<(String, String)>, HttpError> {
+    let mut headers = Vec::new();
+
+    loop {
+        let line = read_line(reader, left)?;
+
+        if line.is_empty() {
+            return Ok(headers);
+        }
+
+        match line.find(':') {
+            Some(pos) => headers.push((
+                line[..pos].trim().to_lowercase(),
+                line[(pos + 1)..].trim().to_string(),
+            )),
+            None => return Err((400, format!("invalid header \"{}\"", line))),
+        }
+    }
+}
+
+// a response without a length goes on until the connection closes, a request doesn't have a body then
+fn read_body<R: BufRead>(
+    reader: &mut R,
+    headers: &[(String, String)],
+    until_end: bool,
+) -> Result<Vec<u8>, HttpError> {
+    let mut body = Vec::new();
+
+    let chunked = get_header(headers, "transfer-encoding")
+        .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"));
+
+    if chunked {
+        loop {
+            let mut left = MAX_HEAD;
+            let line = read_line(reader, &mut left)?;
+            let size = line.split(';').next().unwrap_or("").trim();
+
+            let size = u64::from_str_radix(size, 16)
+                .map_err(|_| (400, format!("invalid chunk size \"{}\"", line)))?;
+
+            if size == 0 {
+                // trailers until the empty line
+                read_headers(reader, &mut left)?;
+                return Ok(body);
+            }
+
+            if body.len() as u64 + size > MAX_BODY {
+                return Err(get_too_large());
+            }
+
+            read_exactly(reader, size, &mut body)?;
+            read_line(reader, &mut left)?;
+        }
+    }
+
+    match get_header(headers, "content-length") {
+        Some(len) => {
+            let len: u64 = len
+                .parse()
+                .map_err(|_| (400, format!("invalid content length \"{}\"", len)))?;
+
+            if len > MAX_BODY {
+                return Err(get_too_large());
+            }
+
+            read_exactly(reader, len, &mut body)?;
+        }
+        None if until_end => {
+            // one more byte than allowed tells if there was too much
+            reader
+                .take(MAX_BODY + 1)
+                .read_to_end(&mut body)
+                .map_err(get_io_error)?;
+
+            if body.len() as u64 > MAX_BODY {
+                return Err(get_too_large());
+            }
+        }
+        None => {}
+    }
+
+    return Ok(body);
+}
+
+// the buffer only grows with what really comes so a wrong length can't make it huge
+fn read_exactly<R: BufRead>(reader: &mut R, len: u64, body: &mut Vec<u8>) -> Result<(), HttpError> {
+    let read = reader.take(len).read_to_end(body).map_err(get_io_error)?;
+
+    if (read as u64) < len {
+        return Err((400, String::from("the body is shorter than its length")));
+    }
+
+    return Ok(());
+}
+
+fn get_too_large() -> HttpError {
+    (413, format!("the body is bigger than {} bytes", MAX_BODY))
+}
+
+fn get_io_error(err: std::io::Error) -> HttpError {
+    match err.kind() {
+        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => {
+            (408, String::from("the request took too long"))
+        }
+        _ => (400, err.to_string()),
+    }
+}
+
+fn get_reason(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
@@ -2,28 +2,38 @@
 use crate::{eprint, eprintln};
 
 pub enum Instruction {
-    ASG,    // assign =
-    NOT,    // not !
-    ADD,    // add +
-    SUB,    // substract -
-    MUL,    // multiply *
-    DIV,    // division /
-    IDIV,   // integer division //
-    MOD,    // modulo %
-    POW,    // power **
-    EQU,    // equal ==
-    NEQU,   // not equal !=
-    XOR,    // exlusif or ^
-    BAND,   // bit and &
-    BOR,    // bit or |
-    AND,    // and &&
-    OR,     // or ||
-    GRE,    // greater-then >
-    LES,    // lesser-then <
-    EGRE,   // greater-then or equal >=
-    ELES,   // lesser-then or equal <=
-    GOTO,   // classic goto with the positino of the intruction to go to
-    GOTOFN, // classic goto with the name of the function instead of the line
+    ASG,     // assign =
+    NOT,     // not !
+    ADD,     // add +
+    SUB,     // substract -
+    MUL,     // multiply *
+    DIV,     // division /
+    IDIV,    // integer division //
+    MOD,     // modulo %
+    POW,     // power **
+    EQU,     // equal ==
+    NEQU,    // not equal !=
+    XOR,     // exlusif or ^
+    BAND,    // bit and &
+    BOR,     // bit or |
+    AND,     // and &&
+    OR,      // or ||
+    GRE,     // greater-then >
+    LES,     // lesser-then <
+    EGRE,    // greater-then or equal >=
+    ELES,    // lesser-then or equal <=
+    IN,      // is contained in
+    RNG,     // range ..
+    IRNG,    // inclusive range ..=
+    COAL,    // take the second value if the first one is null ??
+    TRY,     // the next errors go to the catch of this try instead of stopping everything
+    CATCH,   // end of what the try covers, the error goes in ŧerror
+    GOTO,    // classic goto with the positino of the intruction to go to
+    GOTOFN,  // classic goto with the name of the function instead of the line
+    GOTOMT, // goto the method of the receiver's kind, or the function of the same name with the receiver as first argument
+    OGOTOMT, // like GOTOMT but gives null without calling anything if the receiver is null ?.
+    GET,    // get an element of a tuple by name .
+    YIELD,  // give a value of a generator and pause it until the next value is asked
     END,    // end current process whether it be a function, a thread or the main program
     TUP,    // make into a tuple ,
     COND,   // condition if for while
@@ -63,6 +73,16 @@ impl Instruction {
             Self::STOP => 25,
             Self::UPLV => 25,
             Self::DROPLV => 25,
+            Self::GOTOMT => 26,
+            Self::GET => 27,
+            Self::YIELD => 28,
+            Self::IN => 29,
+            Self::RNG => 30,
+            Self::IRNG => 31,
+            Self::OGOTOMT => 32,
+            Self::COAL => 33,
+            Self::TRY => 34,
+            Self::CATCH => 35,
         }
     }
 }
@@ -98,6 +118,16 @@ impl std::fmt::Display for Instruction {
             Self::STOP => write!(f, "STOP"),
             Self::UPLV => write!(f, "UPLV"),
             Self::DROPLV => write!(f, "DROPLV"),
+            Self::GOTOMT => write!(f, "GOTOMT"),
+            Self::GET => write!(f, "GET"),
+            Self::YIELD => write!(f, "YIELD"),
+            Self::IN => write!(f, "IN"),
+            Self::RNG => write!(f, "RNG"),
+            Self::IRNG => write!(f, "IRNG"),
+            Self::OGOTOMT => write!(f, "OGOTOMT"),
+            Self::COAL => write!(f, "COAL"),
+            Self::TRY => write!(f, "TRY"),
+            Self::CATCH => write!(f, "CATCH"),
         }
     }
 }
@@ -133,6 +163,16 @@ impl std::cmp::PartialEq for Instruction {
             Self::STOP => matches!(other, Self::STOP),
             Self::UPLV => matches!(other, Self::UPLV),
             Self::DROPLV => matches!(other, Self::DROPLV),
+            Self::GOTOMT => matches!(other, Self::GOTOMT),
+            Self::GET => matches!(other, Self::GET),
+            Self::YIELD => matches!(other, Self::YIELD),
+            Self::IN => matches!(other, Self::IN),
+            Self::RNG => matches!(other, Self::RNG),
+            Self::IRNG => matches!(other, Self::IRNG),
+            Self::OGOTOMT => matches!(other, Self::OGOTOMT),
+            Self::COAL => matches!(other, Self::COAL),
+            Self::TRY => matches!(other, Self::TRY),
+            Self::CATCH => matches!(other, Self::CATCH),
         }
     }
 }
@@ -168,6 +208,16 @@ impl Clone for Instruction {
             Self::STOP => Self::STOP,
             Self::UPLV => Self::UPLV,
             Self::DROPLV => Self::DROPLV,
+            Self::GOTOMT => Self::GOTOMT,
+            Self::GET => Self::GET,
+            Self::YIELD => Self::YIELD,
+            Self::IN => Self::IN,
+            Self::RNG => Self::RNG,
+            Self::IRNG => Self::IRNG,
+            Self::OGOTOMT => Self::OGOTOMT,
+            Self::COAL => Self::COAL,
+            Self::TRY => Self::TRY,
+            Self::CATCH => Self::CATCH,
         }
     }
 }
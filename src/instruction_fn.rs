@@ -1,10 +1,12 @@
 use crate::bigint_pow;
 use crate::get_real_name;
 use crate::kind::*;
+use crate::range::*;
 use crate::table::*;
 use crate::variable::*;
 use crate::vec_table::*;
 use num::{BigInt, Signed, Zero};
+use std::cmp::Ordering;
 
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
@@ -44,6 +46,21 @@ pub fn assign(
             vec_table.set_null(real_name);
             table.set_null(name_a, true);
         }
+        Kind::Iterator => {
+            let value = var_b.get_iterator(name_b, table).unwrap();
+            vec_table.set_iterator(real_name, value);
+            table.set_iterator(name_a, value);
+        }
+        Kind::Range => {
+            let value = var_b.get_range(name_b, table).unwrap();
+            vec_table.set_range(real_name, value.clone());
+            table.set_range(name_a, value);
+        }
+        Kind::Handle => {
+            let value = var_b.get_handle(name_b, table).unwrap();
+            vec_table.set_handle(real_name, value);
+            table.set_handle(name_a, value);
+        }
         Kind::Tuple => {
             let pre = vec_table.get(real_name);
             let mut modify = var_a.kind == Kind::Tuple
@@ -308,6 +325,16 @@ fn local_equal(
             Kind::Tuple => {
                 var_a.get_tuple(name_a, table).unwrap() == var_b.get_tuple(name_b, table).unwrap()
             }
+            Kind::Iterator => {
+                var_a.get_iterator(name_a, table).unwrap()
+                    == var_b.get_iterator(name_b, table).unwrap()
+            }
+            Kind::Range => {
+                var_a.get_range(name_a, table).unwrap() == var_b.get_range(name_b, table).unwrap()
+            }
+            Kind::Handle => {
+                var_a.get_handle(name_a, table).unwrap() == var_b.get_handle(name_b, table).unwrap()
+            }
         };
     }
 
@@ -395,3 +422,121 @@ pub fn less(var_a: &Variable, var_b: &Variable, name_a: &str, name_b: &str, tabl
     let (equality, num_a, num_b) = local_some_equal(var_a, var_b, name_a, name_b, table);
     table.set_bool(name_a, equality && (num_a < num_b))
 }
+
+// the order used by < and >, both have to be of the same kind and be numbers, None if they can't be compared
+pub fn compare(
+    var_a: &Variable,
+    var_b: &Variable,
+    name_a: &str,
+    name_b: &str,
+    table: &Table,
+) -> Option<Ordering> {
+    if var_a.kind != var_b.kind {
+        return None;
+    }
+
+    // the same order as with numbers but exact for integers too large to be one
+    if var_a.kind == Kind::BigInt {
+        return Some(
+            table
+                .get_bigint(var_a.pos)
+                .cmp(&table.get_bigint(var_b.pos)),
+        );
+    }
+
+    let num_a = var_a.get_number(name_a, table).ok()?;
+    let num_b = var_b.get_number(name_b, table).ok()?;
+
+    return num_a.partial_cmp(&num_b);
+}
+
+pub fn range(
+    var_a: &Variable,
+    var_b: &Variable,
+    name_a: &str,
+    name_b: &str,
+    table: &mut Table,
+    vec_table: &mut VecTable,
+    inclusive: bool,
+) {
+    let bounds = get_bound(var_a, name_a, table)
+        .and_then(|start| Ok((start, get_bound(var_b, name_b, table)?)));
+
+    match bounds {
+        Ok((start, end)) => table.set_range(name_a, Range::new(start, end, inclusive)),
+        Err(err) => vec_table.set_error(&err),
+    }
+}
+
+// a bound with a fractional part would be silently truncated
+fn get_bound(var: &Variable, name: &str, table: &Table) -> Result<BigInt, String> {
+    if var.kind == Kind::Number && table.get_number(var.pos).fract() != 0.0 {
+        return Err(format!(
+            "the bounds of a range are integers, {} isn't",
+            table.get_number(var.pos)
+        ));
+    }
+
+    return var
+        .get_bigint(name, table)
+        .map_err(|_| format!("the bounds of a range are integers, not a {}", var.kind));
+}
+
+pub fn contained(
+    var_a: &Variable,
+    var_b: &Variable,
+    name_a: &str,
+    name_b: &str,
+    table: &mut Table,
+) {
+    let contain = match var_b.kind {
+        Kind::Range => {
+            let is_integer = match var_a.kind {
+                Kind::BigInt => true,
+                Kind::Number => {
+                    let a = var_a.get_number(name_a, table).unwrap();
+                    a == a.round()
+                }
+                _ => false,
+            };
+
+            is_integer
+                && var_b
+                    .get_range(name_b, table)
+                    .unwrap()
+                    .contains(&var_a.get_bigint(name_a, table).unwrap())
+        }
+        Kind::Tuple => {
+            // the value is put in the tuple so it can be compared with the same rules as ==
+            let mut tuple = var_b.get_tuple(name_b, table).unwrap();
+            let len = tuple.len();
+
+            tuple.push(var_a, name_a, table);
+
+            let name = tuple.get_name(len).to_string();
+            let var = tuple.get(len).clone();
+
+            let mut contain = false;
+
+            for i in 0..len {
+                let name_i = tuple.get_name(i).to_string();
+                let var_i = tuple.get(i).clone();
+
+                if local_equal(&var_i, &var, &name_i, &name, &mut tuple.table) {
+                    contain = true;
+                    break;
+                }
+            }
+
+            contain
+        }
+        Kind::String => var_b
+            .get_string(name_b, table)
+            .unwrap()
+            .contains(var_a.get_string(name_a, table).unwrap().as_str()),
+        Kind::Iterator => false,
+        _ => local_equal(var_a, var_b, name_a, name_b, table),
+    };
+
+    table.set_bool(name_a, contain)
+}
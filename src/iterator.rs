@@ -0,0 +1,189 @@
+use crate::csv::*;
+use crate::default_fn::read_stdin_line;
+use crate::kind::*;
+use crate::process::*;
+use crate::range::*;
+use crate::table::*;
+use crate::tuple::*;
+use crate::vec_table::*;
+use num::{BigInt, One};
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+pub struct Generator {
+    pub process: Process,      // process the function was defined in
+    pub state: Process,        // state of the process between two yield
+    pub pos: usize,            // position of the instruction to resume at
+    pub tables: Vec<Table>, // levels of the generator, the first one is the level of the function
+    pub peeked: Option<Tuple>, // value already generated by has_next but not yet taken by next
+}
+
+impl Generator {
+    pub fn new(process: Process, pos: usize, table: Table) -> Self {
+        Self {
+            state: process.clone(),
+            process: process,
+            pos: pos,
+            tables: vec![table],
+            peeked: None,
+        }
+    }
+}
+
+impl Clone for Generator {
+    fn clone(&self) -> Self {
+        Self {
+            process: self.process.clone(),
+            state: self.state.clone(),
+            pos: self.pos,
+            tables: self.tables.clone(),
+            peeked: self.peeked.clone(),
+        }
+    }
+}
+
+// positions of the iterators held by the variables of a table and by the tuples in it
+pub fn get_held_iterators(table: &Table, held: &mut Vec<usize>) {
+    if table.vec_iterator.is_empty() && table.vec_tuple.is_empty() {
+        return;
+    }
+
+    for var in table.variables.values() {
+        match var.kind {
+            Kind::Iterator => held.push(table.get_iterator(var.pos)),
+            Kind::Tuple => get_held_iterators(&table.vec_tuple[var.pos].table, held),
+            _ => {}
+        }
+    }
+}
+
+pub enum Iter {
+    Tuple(Tuple, usize),
+    String(Vec<char>, usize),
+    Range(Range, BigInt), // the values are only made when asked so a range can be as long as needed
+    Generator(Box<Generator>),
+    Stdin(Option<String>), // the lines are read when asked, the one read by has_next waits in it
+    Csv(Box<CsvReader>),   // the rows of a file read when asked
+    Done,
+}
+
+impl Iter {
+    pub fn next(&mut self, vec_table: &mut VecTable) -> Option<Tuple> {
+        let value = match self {
+            Self::Tuple(tuple, pos) => {
+                if *pos < tuple.len() {
+                    let mut value = Tuple::new();
+                    value.push(tuple.get(*pos), tuple.get_name(*pos), &tuple.table);
+                    *pos += 1;
+
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+            Self::String(chars, pos) => {
+                if *pos < chars.len() {
+                    let mut value = Tuple::new();
+                    value.set_string("", chars[*pos].to_string());
+                    *pos += 1;
+
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+            Self::Range(range, pos) => {
+                if *pos < range.len() {
+                    let mut value = Tuple::new();
+                    value.set_bigint("", range.get(pos));
+                    *pos += BigInt::one();
+
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+            Self::Generator(generator) => match generator.peeked.take() {
+                Some(value) => Some(value),
+                None => Process::resume(generator, vec_table),
+            },
+            Self::Stdin(peeked) => peeked.take().or_else(read_stdin_line).map(|line| {
+                let mut value = Tuple::new();
+                value.set_string("", line);
+                value
+            }),
+            Self::Csv(reader) => match reader.next_row() {
+                Ok(row) => row.map(|row| {
+                    let mut value = Tuple::new();
+                    value.set_tuple("", row);
+                    value
+                }),
+                Err(err) => {
+                    vec_table.set_error(&format!("csv_rows: {}", err));
+                    None
+                }
+            },
+            Self::Done => None,
+        };
+
+        if value.is_none() {
+            *self = Self::Done; // so a finished generator doesn't keep its process alive
+        }
+
+        return value;
+    }
+
+    pub fn has_next(&mut self, vec_table: &mut VecTable) -> bool {
+        let more = match self {
+            Self::Tuple(tuple, pos) => *pos < tuple.len(),
+            Self::String(chars, pos) => *pos < chars.len(),
+            Self::Range(range, pos) => *pos < range.len(),
+            Self::Generator(generator) => {
+                if generator.peeked.is_none() {
+                    generator.peeked = Process::resume(generator, vec_table);
+                }
+
+                generator.peeked.is_some()
+            }
+            Self::Stdin(peeked) => {
+                if peeked.is_none() {
+                    *peeked = read_stdin_line();
+                }
+
+                peeked.is_some()
+            }
+            Self::Csv(reader) => {
+                if reader.peeked.is_none() {
+                    match reader.next_row() {
+                        Ok(row) => reader.peeked = row,
+                        Err(err) => vec_table.set_error(&format!("csv_rows: {}", err)),
+                    }
+                }
+
+                reader.peeked.is_some()
+            }
+            Self::Done => false,
+        };
+
+        if !more {
+            *self = Self::Done;
+        }
+
+        return more;
+    }
+}
+
+impl Clone for Iter {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Tuple(tuple, pos) => Self::Tuple(tuple.clone(), *pos),
+            Self::String(chars, pos) => Self::String(chars.clone(), *pos),
+            Self::Range(range, pos) => Self::Range(range.clone(), pos.clone()),
+            Self::Generator(generator) => Self::Generator(generator.clone()),
+            Self::Stdin(peeked) => Self::Stdin(peeked.clone()),
+            Self::Csv(_) => Self::Done, // the file can't be read from two places at once
+            Self::Done => Self::Done,
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::bigint_pow;
+use crate::tuple::*;
+use num::{BigInt, Integer, Zero};
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+const MAX_DEPTH: usize = 512; // arrays and objects nested deeper are an error rather than a stack overflow
+const MAX_EXPONENT: i64 = 4096; // an integral number with a bigger exponent is a float, not a huge bigint
+
+// objects become tuples with named elements and arrays tuples without names, so {} is () like []
+// integral numbers are read from the text so a bigint keeps all of its digits
+pub fn parse_json(text: &str) -> Result<Tuple, String> {
+    let mut parser = JsonParser {
+        chars: text.chars().collect(),
+        pos: 0,
+    };
+
+    let value = parser.read_value(0)?;
+    parser.skip_whitespace();
+
+    if parser.pos < parser.chars.len() {
+        return Err(parser.unexpected());
+    }
+
+    return Ok(value);
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl JsonParser {
+    // a tuple with the value as its only element
+    fn read_value(&mut self, depth: usize) -> Result<Tuple, String> {
+        let mut value = Tuple::new();
+
+        if depth > MAX_DEPTH {
+            return Err(format!("nested deeper than {} levels", MAX_DEPTH));
+        }
+
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('{') => {
+                self.pos += 1;
+                value.set_tuple("", self.read_object(depth)?);
+            }
+            Some('[') => {
+                self.pos += 1;
+                value.set_tuple("", self.read_array(depth)?);
+            }
+            Some('"') => {
+                self.pos += 1;
+                value.set_string("", self.read_string()?);
+            }
+            Some('-') | Some('0'..='9') => self.read_number(&mut value)?,
+            Some(_) => {
+                if self.read_word("true") {
+                    value.set_bool("", true);
+                } else if self.read_word("false") {
+                    value.set_bool("", false);
+                } else if self.read_word("null") {
+                    value.set_null("");
+                } else {
+                    return Err(self.unexpected());
+                }
+            }
+            None => return Err(self.unexpected()),
+        }
+
+        return Ok(value);
+    }
+
+    // the same key twice keeps the place of the first and the value of the last
+    fn read_object(&mut self, depth: usize) -> Result<Tuple, String> {
+        let mut members: Vec<(String, Tuple)> = Vec::new();
+
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.pos += 1;
+        } else {
+            loop {
+                self.skip_whitespace();
+                self.expect('"')?;
+                let key = self.read_string()?;
+
+                self.skip_whitespace();
+                self.expect(':')?;
+
+                let value = self.read_value(depth + 1)?;
+
+                match members.iter().position(|(other, _)| *other == key) {
+                    Some(pos) => members[pos].1 = value,
+                    None => members.push((key, value)),
+                }
+
+                self.skip_whitespace();
+
+                match self.peek() {
+                    Some(',') => self.pos += 1,
+                    Some('}') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+
+        let mut object = Tuple::new();
+
+        for (key, value) in members.iter() {
+            object.push(value.get(0), key, &value.table);
+        }
+
+        return Ok(object);
+    }
+
+    fn read_array(&mut self, depth: usize) -> Result<Tuple, String> {
+        let mut array = Tuple::new();
+
+        self.skip_whitespace();
+
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(array);
+        }
+
+        loop {
+            let value = self.read_value(depth + 1)?;
+            array.push(value.get(0), "", &value.table);
+
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(array);
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    // after the opening quote
+    fn read_string(&mut self) -> Result<String, String> {
+        let mut string = String::new();
+
+        loop {
+            let ch = match self.peek() {
+                Some(ch) => ch,
+                None => return Err(self.unexpected()),
+            };
+
+            self.pos += 1;
+
+            match ch {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.pos += 1;
+                            string.push(self.read_unicode()?);
+                            continue;
+                        }
+                        _ => return Err(self.unexpected()),
+                    };
+
+                    self.pos += 1;
+                    string.push(escaped);
+                }
+                ch if (ch as u32) < 0x20 => {
+                    self.pos -= 1;
+                    return Err(self.unexpected());
+                }
+                ch => string.push(ch),
+            }
+        }
+    }
+
+    // after \u, a surrogate pair is two of them
+    fn read_unicode(&mut self) -> Result<char, String> {
+        let high = self.read_hex()?;
+
+        if (0xD800..0xDC00).contains(&high) {
+            if self.read_word("\\u") {
+                let low = self.read_hex()?;
+
+                if (0xDC00..0xE000).contains(&low) {
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    return Ok(char::from_u32(code).unwrap_or('\u{FFFD}'));
+                }
+            }
+
+            return Ok('\u{FFFD}');
+        }
+
+        return Ok(char::from_u32(high).unwrap_or('\u{FFFD}'));
+    }
+
+    fn read_hex(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            match self.peek().and_then(|ch| ch.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => return Err(self.unexpected()),
+            }
+
+            self.pos += 1;
+        }
+
+        return Ok(code);
+    }
+
+    // -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn read_number(&mut self, value: &mut Tuple) -> Result<(), String> {
+        let start = self.pos;
+
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+
+        // a number doesn't start with 0 unless it is 0
+        let integer = if self.peek() == Some('0') {
+            self.pos += 1;
+            String::from("0")
+        } else {
+            self.read_digits()
+        };
+
+        if integer.is_empty() {
+            return Err(self.unexpected());
+        }
+
+        let mut fraction = String::new();
+
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            fraction = self.read_digits();
+
+            if fraction.is_empty() {
+                return Err(self.unexpected());
+            }
+        }
+
+        let mut exponent: i64 = 0;
+
+        if let Some('e') | Some('E') = self.peek() {
+            self.pos += 1;
+
+            let negative = self.peek() == Some('-');
+
+            if let Some('-') | Some('+') = self.peek() {
+                self.pos += 1;
+            }
+
+            let digits = self.read_digits();
+
+            if digits.is_empty() {
+                return Err(self.unexpected());
+            }
+
+            exponent = digits.parse().unwrap_or(i64::MAX);
+
+            if negative {
+                exponent = -exponent;
+            }
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        let mut number: BigInt = format!("{}{}", integer, fraction).parse().unwrap();
+        exponent -= fraction.len() as i64;
+
+        // integral numbers are exact as bigints, the others are floats
+        if number.is_zero() {
+        } else if (0..=MAX_EXPONENT).contains(&exponent) {
+            number *= bigint_pow(&BigInt::from(10), &BigInt::from(exponent));
+        } else if exponent < 0 && -exponent <= (integer.len() + fraction.len()) as i64 {
+            let divisor = bigint_pow(&BigInt::from(10), &BigInt::from(-exponent));
+
+            if !number.is_multiple_of(&divisor) {
+                value.set_number("", text.parse().unwrap());
+                return Ok(());
+            }
+
+            number /= divisor;
+        } else {
+            value.set_number("", text.parse().unwrap());
+            return Ok(());
+        }
+
+        if text.starts_with('-') {
+            number = -number;
+        }
+
+        value.set_bigint("", number);
+
+        return Ok(());
+    }
+
+    fn read_digits(&mut self) -> String {
+        let mut digits = String::new();
+
+        while let Some(ch) = self.peek().filter(|ch| ch.is_ascii_digit()) {
+            digits.push(ch);
+            self.pos += 1;
+        }
+
+        return digits;
+    }
+
+    fn read_word(&mut self, word: &str) -> bool {
+        let end = self.pos + word.chars().count();
+
+        if end <= self.chars.len() && self.chars[self.pos..end].iter().copied().eq(word.chars()) {
+            self.pos = end;
+            return true;
+        }
+
+        return false;
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), String> {
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            return Ok(());
+        }
+
+        return Err(self.unexpected());
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        return self.chars.get(self.pos).copied();
+    }
+
+    // with the line and column of the character, counted from 1
+    fn unexpected(&self) -> String {
+        let ch = match self.peek() {
+            Some(ch) => ch,
+            None => return String::from("unexpected end of the text"),
+        };
+
+        let before = &self.chars[..self.pos];
+        let line = before.iter().filter(|ch| **ch == '\n').count() + 1;
+        let column = before.iter().rev().take_while(|ch| **ch != '\n').count() + 1;
+
+        return format!("unexpected character {:?} at {}:{}", ch, line, column);
+    }
+}
@@ -13,6 +13,9 @@ pub enum Kind {
     Null,
     Function,
     Tuple,
+    Iterator,
+    Range,
+    Handle,
 }
 
 impl Kind {
@@ -26,6 +29,9 @@ impl Kind {
             Self::Null => "null",
             Self::Function => "function",
             Self::Tuple => "tuple",
+            Self::Iterator => "iterator",
+            Self::Range => "range",
+            Self::Handle => "handle",
         }
     }
 }
@@ -47,6 +53,9 @@ impl std::cmp::PartialEq for Kind {
             Self::Null => matches!(other, Self::Null),
             Self::Function => matches!(other, Self::Function),
             Self::Tuple => matches!(other, Self::Tuple),
+            Self::Iterator => matches!(other, Self::Iterator),
+            Self::Range => matches!(other, Self::Range),
+            Self::Handle => matches!(other, Self::Handle),
         }
     }
 }
@@ -62,6 +71,9 @@ impl Clone for Kind {
             Self::Null => Self::Null,
             Self::Function => Self::Function,
             Self::Tuple => Self::Tuple,
+            Self::Iterator => Self::Iterator,
+            Self::Range => Self::Range,
+            Self::Handle => Self::Handle,
         }
     }
 }
@@ -78,10 +90,10 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
     }
 
     if *create {
-        if chars[n].is_alphabetic() {
+        if chars[n].is_alphabetic() || chars[n] == '_' {
             n += 1;
 
-            while n < chars.len() && chars[n].is_alphanumeric() {
+            while n < chars.len() && (chars[n].is_alphanumeric() || chars[n] == '_') {
                 n += 1;
             }
 
@@ -98,7 +110,7 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
         }
     } else {
         for o in OPERATORS_STR.iter() {
-            if string.starts_with(o) {
+            if starts_with_operator(&string, o) {
                 kind = Kind::Operator;
                 break;
             }
@@ -126,7 +138,7 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
                     n += 1;
                 }
 
-                if n < chars.len() && chars[n] == '.' {
+                if n + 1 < chars.len() && chars[n] == '.' && chars[n + 1].is_numeric() {
                     kind = Kind::Number;
                     n += 1;
                     while n < chars.len() && chars[n].is_numeric() {
@@ -149,7 +161,8 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
                 kind = Kind::Bool;
                 n += "false".len();
                 break;
-            } else if kind == Kind::Null && (chars[n].is_alphabetic() || chars[n] == CHAR_SEP_NAME)
+            } else if kind == Kind::Null
+                && (chars[n].is_alphabetic() || chars[n] == '_' || chars[n] == CHAR_SEP_NAME)
             {
                 let mut count_sep = 0;
                 if chars[n] == CHAR_SEP_NAME {
@@ -159,6 +172,7 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
 
                 while n < chars.len()
                     && (chars[n].is_alphanumeric()
+                        || chars[n] == '_'
                         || chars[n] == CHAR_SEP_NAME
                         || (count_sep > 0 && count_sep < 3))
                 {
@@ -185,7 +199,10 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
 
                             n += 1;
                         }
-                        '?' => {
+                        // a?.b and a ?? b are operators and not a name taking any amount of arguments
+                        '?' if n + 1 >= chars.len()
+                            || (chars[n + 1] != '.' && chars[n + 1] != '?') =>
+                        {
                             n += 1;
                         }
                         _ => {}
@@ -205,7 +222,7 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
                 let mut max = 0;
 
                 for o in OPERATORS_STR.iter() {
-                    if o.len() > max && string.starts_with(o) {
+                    if o.len() > max && starts_with_operator(&string, o) {
                         max = o.len();
                         opt = o;
                     }
@@ -243,3 +260,19 @@ pub fn get_kind(chars: &[char], create: &mut bool) -> (String, Kind) {
 
     return (string, kind);
 }
+
+// a word operator like in or for has to be followed by something else than a letter so it isn't the start of a name
+fn starts_with_operator(string: &str, operator: &str) -> bool {
+    if !string.starts_with(operator) {
+        return false;
+    }
+
+    if !operator.chars().all(|c| c.is_alphabetic()) {
+        return true;
+    }
+
+    match string.get(operator.len()..).unwrap().chars().next() {
+        Some(c) => !(c.is_alphanumeric() || c == '_'),
+        None => true,
+    }
+}
@@ -1,4 +1,5 @@
-use num::{BigInt, One, Zero};
+use num::{BigInt, Integer, One, Zero};
+use rand::prelude::*;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::BufReader;
@@ -15,14 +16,21 @@ use termion::input::TermRead;
 #[cfg(target_family = "unix")]
 use termion::raw::IntoRawMode;
 
+mod csv;
 mod default_fn;
 mod function;
 mod function_kind;
+mod handle;
+mod http;
 mod instruction;
 mod instruction_fn;
+mod iterator;
+mod json;
 mod kind;
+mod method;
 mod operation;
 mod process;
+mod range;
 mod table;
 mod tuple;
 mod variable;
@@ -167,33 +175,15 @@ pub fn bigint_pow(a: &BigInt, b: &BigInt) -> BigInt {
     let mut b = b.clone();
 
     let mut c = BigInt::one();
-    let mut factor = BigInt::one();
-
-    let mut vec_factor: Vec<BigInt> = Vec::new();
-
-    let mut temp;
 
+    // square and multiply, one bit of the exponent at a time
     while b > BigInt::zero() {
-        temp = &factor + &factor;
-
-        if temp < b {
-            vec_factor.push(a.clone());
-            a *= a.clone();
-            factor = temp;
-        } else {
+        if b.is_odd() {
             c *= &a;
-            b -= &factor;
-
-            while b < factor {
-                match vec_factor.pop() {
-                    Some(e) => {
-                        a = e;
-                        factor -= BigInt::one();
-                    }
-                    None => break,
-                }
-            }
         }
+
+        a = &a * &a;
+        b >>= 1;
     }
 
     return c;
@@ -256,22 +246,64 @@ pub fn decode_string(string: &str) -> String {
     return val;
 }
 
+// a line can hold many statements separated by ; but not the ones inside of a string
+fn split_statements(line: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, ch) in line.char_indices() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == q {
+                    quote = None;
+                }
+            }
+            None => {
+                if ch == '"' || ch == '\'' {
+                    quote = Some(ch);
+                } else if ch == ';' {
+                    statements.push(&line[start..i]);
+                    start = i + 1;
+                }
+            }
+        }
+    }
+
+    statements.push(&line[start..]);
+
+    return statements;
+}
+
 pub fn process_text(content: String, vec_table: &mut VecTable) -> Process {
-    let mut lines: Vec<String> = content
-        .rsplit(|c: char| c == '\n' || c == ';')
-        .filter(|s| s.len() > 0)
-        .map(|s| s.trim_end_matches(';'))
-        .map(|s| s.to_string())
-        .collect();
+    let mut lines: Vec<(String, usize)> = Vec::new();
+
+    for (i, line) in content.split('\n').enumerate() {
+        for s in split_statements(line) {
+            if s.len() > 0 {
+                lines.push((s.to_string(), i + 1));
+            }
+        }
+    }
 
     let mut process_lines = Process::new();
 
     let mut n: usize = 0;
 
-    while lines.len() > 0 {
-        process_lines.from(lines.pop().unwrap(), &mut n, vec_table);
+    for i in 0..(lines.len()) {
+        process_lines.from(lines[i].0.clone(), &mut n, vec_table);
         //process_lines.merge(processed_line);
 
+        // every line number used while processing it comes from this line of the script
+        while process_lines.lines.len() <= n {
+            process_lines.lines.push(lines[i].1);
+        }
+
         n += 1;
     }
 
@@ -280,6 +312,107 @@ pub fn process_text(content: String, vec_table: &mut VecTable) -> Process {
     return process_lines;
 }
 
+fn set_defaults_functions(vec_table: &mut VecTable) {
+    for i in 0..(DEFAULTS_FUNCTIONS.len()) {
+        vec_table.set_function(
+            DEFAULTS_FUNCTIONS_STR[i],
+            Function::new(true, i, Tuple::init(&Vec::from(DEFAULTS_FUNCTIONS_ARGS[i]))),
+        );
+    }
+}
+
+fn find_test_files(path: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+    if path.is_dir() {
+        let mut entries: Vec<std::path::PathBuf> = match std::fs::read_dir(path) {
+            Ok(entries) => entries.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+            Err(_) => return,
+        };
+
+        entries.sort();
+
+        for entry in entries.iter() {
+            find_test_files(entry, files);
+        }
+    } else if path.extension().and_then(|ext| ext.to_str()) == Some("te") {
+        files.push(path.to_path_buf());
+    }
+}
+
+fn new_test_tables(path: &str) -> VecTable {
+    let mut vec_table = VecTable::new();
+    vec_table.get_level(0).set_string("path", path.to_string());
+    set_defaults_functions(&mut vec_table);
+    vec_table.add_level(Table::new());
+
+    return vec_table;
+}
+
+// script test <dir>, runs every test block of the .te files each in its own tables, true if they all passed
+fn run_tests(dir: &str) -> bool {
+    let mut files = Vec::new();
+    find_test_files(std::path::Path::new(dir), &mut files);
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for file in files.iter() {
+        let path = file.to_string_lossy().to_string();
+
+        let content = match readfile(&path) {
+            Ok(content) => content,
+            Err(err) => {
+                println!("FAIL {}: {}", path, err);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let count = {
+            let mut vec_table = new_test_tables(&path);
+            process_text(content.clone(), &mut vec_table).tests.len()
+        };
+
+        for i in 0..count {
+            let mut vec_table = new_test_tables(&path);
+
+            let process_lines = process_text(content.clone(), &mut vec_table);
+            let name = &process_lines.tests[i];
+
+            // the code outside of the test blocks runs first like it would without them
+            process_lines.run(&mut vec_table, 0);
+
+            if vec_table.error.is_none() && vec_table.exit.is_none() {
+                let function_name = format!("{}{}()", TEST_PREFIX, i);
+
+                if let Some((level, var)) = vec_table.get(&function_name) {
+                    let function = var.get_function(&function_name, level).unwrap();
+                    function.run(&Tuple::new(), &process_lines, &mut vec_table);
+                }
+            }
+
+            // exit(0) ends the test early but it still passes
+            if let Some(code) = vec_table.exit.filter(|code| *code != 0) {
+                vec_table.error = Some(format!("exited with code {}", code));
+            }
+
+            match vec_table.error.take() {
+                Some(error) => {
+                    println!("FAIL {}: {}\n     {}", path, name, error);
+                    failed += 1;
+                }
+                None => {
+                    println!("ok   {}: {}", path, name);
+                    passed += 1;
+                }
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed", passed, failed);
+
+    return failed == 0;
+}
+
 #[cfg(feature = "time")]
 fn time_taken(elapsed: Duration) -> String {
     let nano = elapsed.as_nanos() % 1000;
@@ -312,6 +445,26 @@ fn main() {
 
     let mut args: Vec<String> = std::env::args().collect();
 
+    if args.len() > 1 && args[1] == "test" {
+        let dir = if args.len() > 2 { &args[2] } else { "." };
+        std::process::exit(if run_tests(dir) { 0 } else { 1 });
+    }
+
+    // --seed n makes every random value of the run reproducible
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "--seed" && i + 1 < args.len() {
+            match args[i + 1].parse::<u64>() {
+                Ok(seed) => vec_table.rng = StdRng::seed_from_u64(seed),
+                Err(_) => std::eprintln!("invalid seed: {}", args[i + 1]),
+            }
+
+            args.drain(i..(i + 2));
+        } else {
+            i += 1;
+        }
+    }
+
     vec_table.add_level(Table::new());
 
     for i in 1..(args.len()) {
@@ -352,12 +505,7 @@ fn main() {
 
     vec_table.remove_level();
 
-    for i in 0..(DEFAULTS_FUNCTIONS.len()) {
-        vec_table.set_function(
-            DEFAULTS_FUNCTIONS_STR[i],
-            Function::new(true, i, Tuple::init(&Vec::from(DEFAULTS_FUNCTIONS_ARGS[i]))),
-        );
-    }
+    set_defaults_functions(&mut vec_table);
 
     let path;
     let rep = 1;
@@ -377,7 +525,13 @@ fn main() {
     #[cfg(feature = "time")]
     let timer_interpretation = Instant::now();
 
-    let content = readfile(&path).unwrap();
+    let content = match readfile(&path) {
+        Ok(content) => content,
+        Err(err) => {
+            std::eprintln!("can't read {}: {}", path, err);
+            std::process::exit(1);
+        }
+    };
 
     let process_lines = process_text(content, &mut vec_table);
 
@@ -393,6 +547,15 @@ fn main() {
 
         process_lines.run(&mut vec_table, 0);
 
+        if let Some(error) = vec_table.error.take() {
+            std::eprintln!("{}", error);
+            std::process::exit(1);
+        }
+
+        if let Some(code) = vec_table.exit {
+            std::process::exit(code);
+        }
+
         #[cfg(feature = "time")]
         times.push(timer.elapsed());
 
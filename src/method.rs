@@ -0,0 +1,96 @@
+use crate::default_fn::*;
+use crate::function::*;
+use crate::kind::*;
+use crate::tuple::*;
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+// name of the method -> default function it calls with the receiver as first argument
+pub const STRING_METHODS: [(&str, DefaultFunction); 17] = [
+    ("parse", DefaultFunction::Parse),
+    ("len", DefaultFunction::Len),
+    ("slice", DefaultFunction::Slice),
+    ("at", DefaultFunction::At),
+    ("split", DefaultFunction::Split),
+    ("replace", DefaultFunction::Replace),
+    ("find", DefaultFunction::Find),
+    ("starts_with", DefaultFunction::StartsWith),
+    ("ends_with", DefaultFunction::EndsWith),
+    ("upper", DefaultFunction::Upper),
+    ("lower", DefaultFunction::Lower),
+    ("trim", DefaultFunction::Trim),
+    ("substr", DefaultFunction::Substr),
+    ("chars", DefaultFunction::Chars),
+    ("repeat", DefaultFunction::Repeat),
+    ("contains", DefaultFunction::Contains),
+    ("format", DefaultFunction::Format),
+];
+
+pub const NUMBER_METHODS: [(&str, DefaultFunction); 6] = [
+    ("int", DefaultFunction::Int),
+    ("round", DefaultFunction::Round),
+    ("floor", DefaultFunction::Floor),
+    ("ceil", DefaultFunction::Ceil),
+    ("sqrt", DefaultFunction::Sqrt),
+    ("pow", DefaultFunction::Pow),
+];
+
+pub const BOOL_METHODS: [(&str, DefaultFunction); 1] = [("int", DefaultFunction::Int)];
+
+pub const TUPLE_METHODS: [(&str, DefaultFunction); 22] = [
+    ("len", DefaultFunction::Len),
+    ("slice", DefaultFunction::Slice),
+    ("at", DefaultFunction::At),
+    ("join", DefaultFunction::Join),
+    ("contains", DefaultFunction::Contains),
+    ("min", DefaultFunction::Min),
+    ("max", DefaultFunction::Max),
+    ("push", DefaultFunction::Push),
+    ("pop", DefaultFunction::Pop),
+    ("concat", DefaultFunction::Concat),
+    ("reverse", DefaultFunction::Reverse),
+    ("sort", DefaultFunction::Sort),
+    ("sorted", DefaultFunction::Sorted),
+    ("unique", DefaultFunction::Unique),
+    ("index_of", DefaultFunction::IndexOf),
+    ("sum", DefaultFunction::Sum),
+    ("zip", DefaultFunction::Zip),
+    ("enumerate", DefaultFunction::Enumerate),
+    ("map", DefaultFunction::Map),
+    ("filter", DefaultFunction::Filter),
+    ("reduce", DefaultFunction::Reduce),
+    ("iter", DefaultFunction::Iter),
+];
+
+pub const fn get_methods(kind: Kind) -> &'static [(&'static str, DefaultFunction)] {
+    match kind {
+        Kind::String => &STRING_METHODS,
+        Kind::Number | Kind::BigInt => &NUMBER_METHODS,
+        Kind::Bool => &BOOL_METHODS,
+        Kind::Tuple => &TUPLE_METHODS,
+        Kind::Operator
+        | Kind::Null
+        | Kind::Function
+        | Kind::Iterator
+        | Kind::Range
+        | Kind::Handle => &[],
+    }
+}
+
+// if the kind doesn't have the method the caller is meant to fall back on the function of the same name
+pub fn get_method(kind: Kind, name: &str) -> Option<Function> {
+    for (method, default_fn) in get_methods(kind).iter() {
+        if *method == name {
+            let pos = default_fn.get_pos();
+
+            return Some(Function::new(
+                true,
+                pos,
+                Tuple::init(&Vec::from(DEFAULTS_FUNCTIONS_ARGS[pos])),
+            ));
+        }
+    }
+
+    return None;
+}
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
 
-pub const OPERATORS: [Operator; 45] = [
+pub const OPERATORS: [Operator; 52] = [
     Operator::PowAsign,
     Operator::AddAsign,
     Operator::SubAsign,
@@ -15,6 +15,7 @@ pub const OPERATORS: [Operator; 45] = [
     Operator::Asign,
     Operator::And,
     Operator::Or,
+    Operator::Coalesce,
     Operator::Pow,
     Operator::Mul,
     Operator::Div,
@@ -32,11 +33,17 @@ pub const OPERATORS: [Operator; 45] = [
     Operator::GreaterEqual,
     Operator::LesserEqual,
     Operator::NotEqual,
+    Operator::In,
+    Operator::Range,
+    Operator::RangeInclusive,
     Operator::SeparatorTuple,
     Operator::Return,
+    Operator::Yield,
     Operator::End,
     Operator::SetFunction,
     Operator::UseFunction,
+    Operator::Access,
+    Operator::OptionalAccess,
     Operator::If,
     Operator::Else,
     Operator::Elif,
@@ -95,6 +102,13 @@ pub const OPERATORS_STR: [&str; OPERATORS.len()] = [
     OPERATORS[42].get_str(),
     OPERATORS[43].get_str(),
     OPERATORS[44].get_str(),
+    OPERATORS[45].get_str(),
+    OPERATORS[46].get_str(),
+    OPERATORS[47].get_str(),
+    OPERATORS[48].get_str(),
+    OPERATORS[49].get_str(),
+    OPERATORS[50].get_str(),
+    OPERATORS[51].get_str(),
 ];
 
 pub enum Operator {
@@ -111,6 +125,7 @@ pub enum Operator {
     Asign,
     And,
     Or,
+    Coalesce,
     Pow,
     Mul,
     Div,
@@ -128,11 +143,17 @@ pub enum Operator {
     GreaterEqual,
     LesserEqual,
     NotEqual,
+    In,
+    Range,
+    RangeInclusive,
     SeparatorTuple,
     Return,
+    Yield,
     End,
     SetFunction,
     UseFunction,
+    Access,
+    OptionalAccess,
     If,
     Else,
     Elif,
@@ -146,23 +167,25 @@ pub enum Operator {
 }
 
 // Priority
-pub const P_USE_FUNCTION: usize = 14;
-pub const P_NOT: usize = 13; // !
-pub const P_POW: usize = 12; // **
-pub const P_MUL_DIV_MOD: usize = 11; // * / %
-pub const P_ADD_SUB: usize = 10; // + -
-pub const P_BIT_AND: usize = 9; // &
-pub const P_XOR: usize = 8; // ^
-pub const P_BIT_OR: usize = 7; // |
-pub const P_COMPARAISON: usize = 6; // == != < > <= >=
-pub const P_AND: usize = 5; // &&
-pub const P_OR: usize = 4; // ||
+pub const P_USE_FUNCTION: usize = 16; // function call, . ?.
+pub const P_NOT: usize = 15; // !
+pub const P_POW: usize = 14; // **
+pub const P_MUL_DIV_MOD: usize = 13; // * / %
+pub const P_ADD_SUB: usize = 12; // + -
+pub const P_BIT_AND: usize = 11; // &
+pub const P_XOR: usize = 10; // ^
+pub const P_BIT_OR: usize = 9; // |
+pub const P_RANGE: usize = 8; // .. ..=
+pub const P_COMPARAISON: usize = 7; // == != < > <= >= in
+pub const P_AND: usize = 6; // &&
+pub const P_OR: usize = 5; // ||
+pub const P_COALESCE: usize = 4; // ??
 pub const P_SEPARATOR: usize = 3;
 pub const P_ASSIGNEMENT: usize = 2; // = += -= *= /= %= &= |= ^= **=
 pub const P_CONDITION: usize = 1; // if else
-pub const P_RETURN_FUNCTION: usize = 0; // return
+pub const P_RETURN_FUNCTION: usize = 0; // return yield
 
-pub const LEVELS_OF_PRIORITY: usize = 15;
+pub const LEVELS_OF_PRIORITY: usize = 17;
 
 impl Operator {
     pub fn from_string(string: &str) -> Option<Self> {
@@ -190,6 +213,7 @@ impl Operator {
             Self::Asign => P_ASSIGNEMENT,
             Self::And => P_AND,
             Self::Or => P_OR,
+            Self::Coalesce => P_COALESCE,
             Self::Pow => P_POW,
             Self::Mul => P_MUL_DIV_MOD,
             Self::Div => P_MUL_DIV_MOD,
@@ -207,11 +231,17 @@ impl Operator {
             Self::GreaterEqual => P_COMPARAISON,
             Self::LesserEqual => P_COMPARAISON,
             Self::NotEqual => P_COMPARAISON,
+            Self::In => P_COMPARAISON,
+            Self::Range => P_RANGE,
+            Self::RangeInclusive => P_RANGE,
             Self::SeparatorTuple => P_SEPARATOR,
             Self::Return => P_RETURN_FUNCTION,
+            Self::Yield => P_RETURN_FUNCTION,
             Self::End => P_RETURN_FUNCTION,
             Self::SetFunction => P_RETURN_FUNCTION,
             Self::UseFunction => P_USE_FUNCTION,
+            Self::Access => P_USE_FUNCTION,
+            Self::OptionalAccess => P_USE_FUNCTION,
             Self::If => P_CONDITION,
             Self::Else => P_CONDITION,
             Self::Elif => P_CONDITION,
@@ -240,6 +270,7 @@ impl Operator {
             Self::Asign => "=",
             Self::And => "&&",
             Self::Or => "||",
+            Self::Coalesce => "??",
             Self::Pow => "**",
             Self::Mul => "*",
             Self::Div => "/",
@@ -257,11 +288,17 @@ impl Operator {
             Self::GreaterEqual => ">=",
             Self::LesserEqual => "<=",
             Self::NotEqual => "!=",
+            Self::In => "in",
+            Self::Range => "..",
+            Self::RangeInclusive => "..=",
             Self::SeparatorTuple => ",",
             Self::Return => "return",
+            Self::Yield => "yield",
             Self::End => "end",
             Self::SetFunction => "fn",
             Self::UseFunction => "☺",
+            Self::Access => ".",
+            Self::OptionalAccess => "?.",
             Self::If => "if",
             Self::Else => "else",
             Self::Elif => "elif",
@@ -308,6 +345,7 @@ impl std::cmp::PartialEq for Operator {
             Self::Asign => matches!(other, Self::Asign),
             Self::And => matches!(other, Self::And),
             Self::Or => matches!(other, Self::Or),
+            Self::Coalesce => matches!(other, Self::Coalesce),
             Self::Pow => matches!(other, Self::Pow),
             Self::Mul => matches!(other, Self::Mul),
             Self::Div => matches!(other, Self::Div),
@@ -325,11 +363,17 @@ impl std::cmp::PartialEq for Operator {
             Self::GreaterEqual => matches!(other, Self::GreaterEqual),
             Self::LesserEqual => matches!(other, Self::LesserEqual),
             Self::NotEqual => matches!(other, Self::NotEqual),
+            Self::In => matches!(other, Self::In),
+            Self::Range => matches!(other, Self::Range),
+            Self::RangeInclusive => matches!(other, Self::RangeInclusive),
             Self::SeparatorTuple => matches!(other, Self::SeparatorTuple),
             Self::Return => matches!(other, Self::Return),
+            Self::Yield => matches!(other, Self::Yield),
             Self::End => matches!(other, Self::End),
             Self::SetFunction => matches!(other, Self::SetFunction),
             Self::UseFunction => matches!(other, Self::UseFunction),
+            Self::Access => matches!(other, Self::Access),
+            Self::OptionalAccess => matches!(other, Self::OptionalAccess),
             Self::If => matches!(other, Self::If),
             Self::Else => matches!(other, Self::Else),
             Self::Elif => matches!(other, Self::Elif),
@@ -360,6 +404,7 @@ impl Clone for Operator {
             Self::Asign => Self::Asign,
             Self::And => Self::And,
             Self::Or => Self::Or,
+            Self::Coalesce => Self::Coalesce,
             Self::Pow => Self::Pow,
             Self::Mul => Self::Mul,
             Self::Div => Self::Div,
@@ -377,11 +422,17 @@ impl Clone for Operator {
             Self::GreaterEqual => Self::GreaterEqual,
             Self::LesserEqual => Self::LesserEqual,
             Self::NotEqual => Self::NotEqual,
+            Self::In => Self::In,
+            Self::Range => Self::Range,
+            Self::RangeInclusive => Self::RangeInclusive,
             Self::SeparatorTuple => Self::SeparatorTuple,
             Self::Return => Self::Return,
+            Self::Yield => Self::Yield,
             Self::End => Self::End,
             Self::SetFunction => Self::SetFunction,
             Self::UseFunction => Self::UseFunction,
+            Self::Access => Self::Access,
+            Self::OptionalAccess => Self::OptionalAccess,
             Self::If => Self::If,
             Self::Else => Self::Else,
             Self::Elif => Self::Elif,
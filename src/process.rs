@@ -1,45 +1,66 @@
 use crate::function::*;
 use crate::instruction::*;
 use crate::instruction_fn::*;
+use crate::iterator::*;
 use crate::kind::*;
+use crate::method::*;
 use crate::operation::*;
 use crate::table::*;
 use crate::tuple::*;
 use crate::variable::*;
 use crate::vec_table::*;
 use crate::CHAR_SEP_NAME;
-use crate::{function_kind::FunctionKind, string_to_usize, usize_to_string};
+use crate::{decode_string, function_kind::FunctionKind, string_to_usize, usize_to_string};
+use std::collections::HashSet;
+use std::rc::Rc;
 
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
 
+pub const TEST_PREFIX: &str = "ŧest"; // test blocks are functions named with it and their position
+pub const CAUGHT: &str = "ŧerror"; // variable where catch puts the error, null if there was none
+pub const KEYWORD_PREFIX: &str = "ŧkey_"; // f(name = value) is f((ŧkey_name = value)) so the function can tell it apart
+
 pub struct Process {
     pub table: Table,
-    pub instructions: Vec<(Instruction, Vec<String>)>,
+    pub instructions: Rc<Vec<(Instruction, Vec<String>)>>, // shared by the copies made to run a function
     incomplete_function: Vec<(usize, usize, usize, FunctionKind)>,
     incomplete_loop: Vec<(usize, usize, usize)>,
     tables: Vec<Table>,
     loop_counter: Vec<usize>,
+    level: usize, // length of the table outside of any function, levels are dropped relative to it
+    pub lines: Vec<usize>, // line of the script of each line number used in the names
+    pub tests: Vec<String>, // names of the test blocks, the n-th one being the function ŧestn
+    handlers: Vec<(usize, usize, usize)>, // position of the try, length of vec_table and of tables when it started
+    tries: Vec<usize>, // blocks open at each try still waiting for its catch, an end at that depth closes it without one
+    single_arguments: HashSet<String>, // the only argument of a call, given whole even if it is a tuple
+    for_loops: Vec<(usize, String)>, // blocks open at each for and its iterator, dropped at the end of its loop
 }
 
 impl Process {
     pub fn new() -> Self {
         Process {
             table: Table::new(),
-            instructions: Vec::new(),
+            instructions: Rc::new(Vec::new()),
             incomplete_function: Vec::new(),
             incomplete_loop: Vec::new(),
             tables: Vec::new(),
             loop_counter: Vec::new(),
+            level: 1,
+            lines: Vec::new(),
+            tests: Vec::new(),
+            handlers: Vec::new(),
+            tries: Vec::new(),
+            single_arguments: HashSet::new(),
+            for_loops: Vec::new(),
         }
     }
 
     pub fn merge(&mut self, other: Self) {
-        for element in other.instructions.into_iter() {
-            self.instructions.push(element);
-        }
+        Rc::make_mut(&mut self.instructions).extend(other.instructions.iter().cloned());
 
         self.table.merge(other.table);
+        self.single_arguments.extend(other.single_arguments);
     }
 
     #[cfg(feature = "print")]
@@ -79,7 +100,7 @@ impl Process {
             let mut do_a = true;
             let mut do_b = true;
 
-            let mut can_a = true;
+            let mut quote = "";
 
             let mut do_b_pos = 0;
 
@@ -96,16 +117,14 @@ impl Process {
                         }
                     }
                     "\"" | "\'" => {
-                        if (do_b || (i - do_b_pos > 1))
-                            && (ch == "\"" || can_a)
-                            && (ch == "\'" || !can_a)
-                        {
+                        // only the kind of quote that opened the string closes it
+                        if (do_b || (i - do_b_pos > 1)) && (do_a || ch == quote) {
                             do_a = !do_a;
 
                             if do_a {
                                 do_b = true;
                             } else {
-                                can_a = ch == "\'";
+                                quote = ch;
                             }
                         }
                     }
@@ -120,6 +139,101 @@ impl Process {
 
         line = line.trim().to_string();
 
+        if self.incomplete_function.len() == 0 {
+            self.level = vec_table.len();
+        }
+
+        line = replace_index(&line);
+        line = replace_keywords(&line);
+
+        // test "name" ... end is a function without arguments only called by `script test`
+        if line.starts_with("test ") {
+            let name = line.get(5..).unwrap().trim();
+
+            if name.starts_with('\"') || name.starts_with('\'') {
+                let name = decode_string(name);
+                line = format!("fn {}{}()", TEST_PREFIX, self.tests.len());
+                self.tests.push(name);
+            }
+        }
+
+        // try ... catch name ... end runs the catch block with the error in name only if the try block failed
+        if line == "try" {
+            self.push_instruction((Instruction::TRY, Vec::new()));
+            self.tries.push(self.incomplete_function.len());
+            return (String::new(), 0);
+        } else if line == "catch" || line.starts_with("catch ") {
+            self.push_instruction((Instruction::CATCH, Vec::new()));
+            self.tries.pop();
+
+            let name = line.get(5..).unwrap().trim().to_string();
+            let mut lines = Vec::new();
+
+            if name.len() > 0 {
+                lines.push(format!("{} = {}", name, CAUGHT));
+            }
+
+            lines.push(format!("if {} != null", CAUGHT));
+
+            for i in 0..(lines.len()) {
+                *line_num += 1;
+                self.from(lines[i].clone(), line_num, vec_table);
+            }
+
+            return (String::new(), 0);
+        }
+
+        // try ... end without a catch just drops the error, as if it had an empty catch
+        if line == "end" && self.tries.last() == Some(&self.incomplete_function.len()) {
+            self.from(String::from("catch"), line_num, vec_table);
+        }
+
+        // the end of the loop of a for, after a break too, lets go of its iterator
+        if line == "end"
+            && self.for_loops.last().map(|(depth, _)| depth + 1)
+                == Some(self.incomplete_function.len())
+        {
+            let (_, iterator) = self.for_loops.pop().unwrap();
+
+            self.from(line, line_num, vec_table);
+
+            *line_num += 1;
+            self.from(format!("{} = null", iterator), line_num, vec_table);
+
+            return (String::new(), 0);
+        }
+
+        // for name in value ... end is a loop taking the values of an iterator until there is none left
+        if line.starts_with("for ") {
+            if let Some(n) = line.find(" in ") {
+                let name = line.get(4..n).unwrap().trim().to_string();
+                let value = line.get((n + 4)..).unwrap().trim().to_string();
+                let iterator = format!("ƒor{}", line_num);
+
+                self.for_loops
+                    .push((self.incomplete_function.len(), iterator.clone()));
+
+                let lines = [
+                    format!("{} = iter({})", iterator, value),
+                    String::from("loop"),
+                    format!("if !has_next({})", iterator),
+                    String::from("break"),
+                    String::from("end"),
+                    format!("{} = next({})", name, iterator),
+                ];
+
+                for i in 0..(lines.len()) {
+                    if i > 0 {
+                        *line_num += 1;
+                    }
+
+                    self.from(lines[i].clone(), line_num, vec_table);
+                }
+
+                return (String::new(), 0);
+            }
+        }
+
         while at < line.len() {
             let mut pos_inc = 0;
             let mut pos_dec = 0;
@@ -130,7 +244,7 @@ impl Process {
             let mut do_a = true;
             let mut do_b = true;
 
-            let mut can_a = true;
+            let mut quote = "";
 
             let mut do_b_pos = 0;
 
@@ -143,7 +257,7 @@ impl Process {
                             count_inc += 1;
 
                             if count_inc == 1 {
-                                pos_inc = i;
+                                pos_inc = at + i;
                             }
                         }
                     }
@@ -152,22 +266,20 @@ impl Process {
                             count_dec += 1;
 
                             if count_dec == count_inc {
-                                pos_dec = i;
+                                pos_dec = at + i;
                                 break;
                             }
                         }
                     }
                     "\"" | "\'" => {
-                        if (do_b || (i - do_b_pos > 1))
-                            && (ch == "\"" || can_a)
-                            && (ch == "\'" || !can_a)
-                        {
+                        // only the kind of quote that opened the string closes it
+                        if (do_b || (i - do_b_pos > 1)) && (do_a || ch == quote) {
                             do_a = !do_a;
 
                             if do_a {
                                 do_b = true;
                             } else {
-                                can_a = ch == "\'";
+                                quote = ch;
                             }
                         }
                     }
@@ -180,15 +292,27 @@ impl Process {
             }
 
             if count_dec == count_inc && count_inc > 0 {
-                let buf = self.from(
-                    line.get((pos_inc + 1)..pos_dec).unwrap().to_string(),
-                    line_num,
-                    vec_table,
-                );
+                let content = line.get((pos_inc + 1)..pos_dec).unwrap().to_string();
+
+                let call = line
+                    .get(..pos_inc)
+                    .unwrap()
+                    .chars()
+                    .last()
+                    .is_some_and(|ch| ch.is_alphanumeric() || ch == '_');
+
+                let single = call && !content.trim().is_empty() && !has_comma(&content);
+
+                let buf = self.from(content, line_num, vec_table);
 
                 let mut name = buf.0;
                 operation_count += buf.1;
 
+                // f((1, 2)) gives the tuple as one argument like f(t) does, only f(1, 2) spreads it
+                if single {
+                    self.single_arguments.insert(name.clone());
+                }
+
                 let real_name = get_real_name(&name);
 
                 if real_name.contains("(") {
@@ -204,7 +328,7 @@ impl Process {
                     line.get(pos_dec..).unwrap(),
                 );
 
-                at = mult.0.len() + mult.1.len();
+                at = mult.0.len() + mult.1.len() + 1; // after the closing parenthesis
                 line = format!("{}{}{}", mult.0, mult.1, mult.2);
             } else {
                 break;
@@ -224,11 +348,28 @@ impl Process {
             line_num: &mut usize,
             create: &mut bool,
         ) {
+            let access = *last_kind == Kind::Operator
+                && (last_raw_value.as_str() == Operator::Access.get_str()
+                    || last_raw_value.as_str() == Operator::OptionalAccess.get_str());
+
             *last_kind = kind;
             *last_raw_value = raw_value.to_string();
 
             if raw_value != " " {
-                if kind == Kind::Function {
+                if access && kind == Kind::Null && !raw_value.contains(CHAR_SEP_NAME) {
+                    // the name after a . is the name of the element and not a variable
+                    add_variable(
+                        table,
+                        last_kind,
+                        last_raw_value,
+                        entry_list,
+                        operator_order,
+                        &format!("\"{}\"", raw_value),
+                        Kind::String,
+                        line_num,
+                        create,
+                    );
+                } else if kind == Kind::Function {
                     add_variable(
                         table,
                         last_kind,
@@ -258,7 +399,7 @@ impl Process {
                         );
 
                         *create = false;
-                    } else {
+                    } else if !access {
                         add_variable(
                             table,
                             last_kind,
@@ -414,7 +555,7 @@ impl Process {
                     );
                 }
 
-                n += raw_value.len();
+                n += raw_value.chars().count();
             } else {
                 n += 1;
             }
@@ -431,6 +572,11 @@ impl Process {
                 let diff: usize = {
                     if i == P_NOT {
                         1
+                    } else if (OPERATORS[table.get(&entry_list[pos]).pos] == Operator::Access
+                        || OPERATORS[table.get(&entry_list[pos]).pos] == Operator::OptionalAccess)
+                        && get_real_name(&entry_list[pos + 1]).contains("(")
+                    {
+                        3 // receiver . method arguments
                     } else {
                         2
                     }
@@ -484,26 +630,36 @@ impl Process {
         let mut name = String::new();
         let mut find = true;
 
-        for p in entry_list.iter() {
+        for i in 0..(entry_list.len()) {
+            let p = &entry_list[i];
             let var = table.get(p);
 
             if var.kind == Kind::Operator {
-                if var.get_operator("").unwrap() != Operator::SeparatorTuple {
-                    operation_count += 1;
-                }
+                operation_count += 1;
             } else if find {
                 find = false;
                 name = p.clone();
+
+                // the result of a . is put in the name of the receiver without its variable name
+                if i + 1 < entry_list.len()
+                    && table.get(&entry_list[i + 1]).kind == Kind::Operator
+                    && (table.get(&entry_list[i + 1]).get_operator("").unwrap() == Operator::Access
+                        || table.get(&entry_list[i + 1]).get_operator("").unwrap()
+                            == Operator::OptionalAccess)
+                {
+                    name = name.trim_start_matches(get_real_name(&name)).to_string();
+                }
             }
         }
 
         self.convert(
             &mut table,
+            &mut name,
             &mut entry_list,
             &mut operator_order,
             vec_table,
             start_pos,
-            operation_count - 1, // -1 because of if and elif being operation
+            operation_count.wrapping_sub(1), // -1 because of if and elif being operation, a line without any wraps
         );
 
         table.clear_operator();
@@ -571,11 +727,26 @@ impl Process {
                         Kind::Tuple => {
                             table.set_tuple(name, var.get_tuple(real_name, level).unwrap())
                         }
+                        Kind::Iterator => {
+                            table.set_iterator(name, var.get_iterator(real_name, level).unwrap())
+                        }
+                        Kind::Range => {
+                            table.set_range(name, var.get_range(real_name, level).unwrap())
+                        }
+                        Kind::Handle => {
+                            table.set_handle(name, var.get_handle(real_name, level).unwrap())
+                        }
                         Kind::Null => table.set_null(name, true),
                         Kind::Operator => {}
                         Kind::Function => {}
                     },
-                    None => {}
+                    // a function used as a value, so it can be given to another function
+                    None => match vec_table.get(&format!("{}()", real_name)) {
+                        Some((level, var)) if var.kind == Kind::Function => {
+                            table.set_function(name, level.get_function(var.pos));
+                        }
+                        _ => {}
+                    },
                 };
             }
             Kind::Tuple => {
@@ -599,9 +770,85 @@ impl Process {
         eprintln!("\n{}\t: {}\t: {}\n", "name", "kind", "value");
 
         let mut j = pos;
+        let level = vec_table.len();
+
+        return self.execute(&mut this, &mut j, level, vec_table).0;
+    }
+
+    // the levels of the generator are put back on top of the table while it runs
+    pub fn resume(generator: &mut Generator, vec_table: &mut VecTable) -> Option<Tuple> {
+        let level = vec_table.len();
 
-        while j < this.instructions.len() {
-            let (instruction, names) = this.instructions[j].clone();
+        for table in generator.tables.drain(..) {
+            vec_table.add_level(table);
+        }
+
+        let (tuple, finished) = generator.process.execute(
+            &mut generator.state,
+            &mut generator.pos,
+            level + 1,
+            vec_table,
+        );
+
+        while vec_table.len() > level {
+            generator.tables.insert(0, vec_table.remove_level());
+        }
+
+        if finished {
+            let mut dropped = Vec::new();
+
+            for table in generator.tables.drain(..) {
+                get_held_iterators(&table, &mut dropped);
+            }
+
+            vec_table.drop_iterators(dropped);
+            return None;
+        }
+
+        return Some(tuple);
+    }
+
+    // level is the length of the table when the function started, the bool is false if it stopped at a yield
+    fn execute(
+        &self,
+        this: &mut Process,
+        j: &mut usize,
+        level: usize,
+        vec_table: &mut VecTable,
+    ) -> (Tuple, bool) {
+        while *j < this.instructions.len() {
+            // exit() was called, the levels added since the start are given back on the way out
+            if vec_table.exit.is_some() {
+                while vec_table.len() > level {
+                    vec_table.remove_level();
+                }
+
+                return (Tuple::new(), true);
+            }
+
+            // something failed, everything stops until it gets to a try
+            if vec_table.error.is_some() {
+                match this.handlers.pop() {
+                    Some((position, len, tables)) => {
+                        while vec_table.len() > len {
+                            vec_table.drop_level();
+                        }
+
+                        while this.tables.len() > tables {
+                            this.table = this.tables.pop().unwrap();
+                        }
+
+                        let error = vec_table.error.take().unwrap();
+                        vec_table.set_string(CAUGHT, error);
+
+                        *j = Self::get_catch(&this.instructions, position) + 1;
+                        continue;
+                    }
+                    None => return (Tuple::new(), true),
+                }
+            }
+
+            let (instruction, names) = this.instructions[*j].clone();
             let mut vars: Vec<Variable> = Vec::with_capacity(names.len());
 
             for name in names.iter() {
@@ -614,6 +861,8 @@ impl Process {
 
             match instruction {
                 Instruction::ASG => {
+                    let held = vec_table.get_held_iterators(get_real_name(&names[0]));
+
                     assign(
                         &vars[0],
                         &vars[1],
@@ -622,6 +871,8 @@ impl Process {
                         &mut this.table,
                         vec_table,
                     );
+
+                    vec_table.drop_iterators(held);
                 }
                 Instruction::NOT => {
                     this.table.set_bool(
@@ -677,9 +928,42 @@ impl Process {
                 Instruction::ELES => {
                     less_equal(&vars[0], &vars[1], &names[0], &names[1], &mut this.table)
                 }
+                Instruction::COAL => {
+                    if vars[0].kind == Kind::Null {
+                        let tuple = Tuple::from(&vec![names[1].as_str()], &this.table);
+                        Self::set_result(&mut this.table, &names[0], tuple);
+                    }
+                }
+                Instruction::IN => {
+                    contained(&vars[0], &vars[1], &names[0], &names[1], &mut this.table)
+                }
+                Instruction::RNG => {
+                    vec_table.line = self.get_line(&names[0]);
+                    range(
+                        &vars[0],
+                        &vars[1],
+                        &names[0],
+                        &names[1],
+                        &mut this.table,
+                        vec_table,
+                        false,
+                    )
+                }
+                Instruction::IRNG => {
+                    vec_table.line = self.get_line(&names[0]);
+                    range(
+                        &vars[0],
+                        &vars[1],
+                        &names[0],
+                        &names[1],
+                        &mut this.table,
+                        vec_table,
+                        true,
+                    )
+                }
                 Instruction::GOTO => {
                     let position = string_to_usize(&names[0]);
-                    j = position;
+                    *j = position;
                     continue; // to not to "j += 1;"
                 }
                 Instruction::GOTOFN => {
@@ -688,7 +972,11 @@ impl Process {
 
                     let tuple = {
                         if names.len() > 1 {
-                            if vars[1].kind == Kind::Tuple {
+                            // only arguments separated by commas are spread, f(t) and f((1, 2)) give one tuple
+                            if vars[1].kind == Kind::Tuple
+                                && self.table.get(&names[1]).kind == Kind::Tuple
+                                && !self.single_arguments.contains(&names[1])
+                            {
                                 this.table.get_tuple(vars[1].pos)
                             } else {
                                 Tuple::from(
@@ -703,66 +991,119 @@ impl Process {
 
                     let name = name.trim_start_matches(real_name);
 
-                    match vec_table.get(real_name) {
-                        Some((level, var)) => {
-                            let function = var.get_function(real_name, level).unwrap();
-                            let tuple_b = function.run(&tuple, self, vec_table);
+                    vec_table.line = self.get_line(&names[0]);
 
-                            match tuple_b.len() {
-                                0 => this.table.set_null(&name, true),
-                                1 => {
-                                    let var = tuple_b.get(0);
-
-                                    match var.kind {
-                                        Kind::String => this
-                                            .table
-                                            .set_string(&name, tuple_b.table.get_string(var.pos)),
-                                        Kind::Number => this
-                                            .table
-                                            .set_number(&name, tuple_b.table.get_number(var.pos)),
-                                        Kind::BigInt => this
-                                            .table
-                                            .set_bigint(&name, tuple_b.table.get_bigint(var.pos)),
-                                        Kind::Bool => this
-                                            .table
-                                            .set_bool(&name, tuple_b.table.get_bool(var.pos)),
-                                        Kind::Tuple => this
-                                            .table
-                                            .set_tuple(&name, tuple_b.table.get_tuple(var.pos)),
-                                        Kind::Function => {}
-                                        Kind::Operator => {}
-                                        Kind::Null => this.table.set_null(&name, true),
-                                    };
-                                }
-                                _ => this.table.set_tuple(&name, tuple_b),
+                    // a function given as an argument is stored without the parentheses
+                    let function = match vec_table.get(real_name) {
+                        Some((level, var)) => Some(var.get_function(real_name, level).unwrap()),
+                        None => match vec_table.get(real_name.trim_end_matches("()")) {
+                            Some((level, var)) if var.kind == Kind::Function => {
+                                Some(level.get_function(var.pos))
                             }
+                            _ => None,
+                        },
+                    };
+
+                    match function {
+                        Some(function) => {
+                            let tuple_b = function.run(&tuple, self, vec_table);
+
+                            Self::set_result(&mut this.table, name, tuple_b);
                         }
                         None => {}
                     }
                 }
-                Instruction::END => {
-                    if names.len() > 1 && this.table.get(&names[0]).kind == Kind::Tuple {
-                        return this
-                            .table
-                            .get(&names[0])
-                            .get_tuple(&names[0], &this.table)
-                            .unwrap();
-                    } else {
-                        return Tuple::from(
-                            &names.iter().map(|n| n.as_str()).collect(),
-                            &this.table,
-                        );
+                Instruction::OGOTOMT if vars[0].kind == Kind::Null => {
+                    let name = names[0].trim_start_matches(get_real_name(&names[0]));
+                    this.table.set_null(name, true);
+                }
+                Instruction::GOTOMT | Instruction::OGOTOMT => {
+                    let mut tuple = Tuple::new();
+                    tuple.push(&vars[0], &names[0], &this.table);
+
+                    if self.table.get(&names[2]).kind == Kind::Tuple
+                        && !self.single_arguments.contains(&names[2])
+                    {
+                        let arguments = this.table.get_tuple(vars[2].pos);
+
+                        for i in 0..(arguments.len()) {
+                            tuple.push(arguments.get(i), arguments.get_name(i), &arguments.table);
+                        }
+                    } else if vars[2].kind != Kind::Null || get_real_name(&names[2]).len() > 0 {
+                        tuple.push(&vars[2], &names[2], &this.table);
+                    }
+
+                    let real_name = get_real_name(&names[1]);
+                    let method = real_name.trim_end_matches("()");
+
+                    let function = match get_method(vars[0].kind, method) {
+                        Some(function) => Some(function),
+                        None => vec_table
+                            .get(real_name)
+                            .map(|(level, var)| var.get_function(real_name, level).unwrap()),
+                    };
+
+                    let name = names[0].trim_start_matches(get_real_name(&names[0]));
+
+                    vec_table.line = self.get_line(&names[1]);
+
+                    match function {
+                        Some(function) => {
+                            let tuple_b = function.run(&tuple, self, vec_table);
+                            Self::set_result(&mut this.table, name, tuple_b);
+                        }
+                        None => this.table.set_null(name, true),
                     }
                 }
+                Instruction::GET => {
+                    let key = vars[1].get_string(&names[1], &this.table).unwrap();
+                    let mut tuple_b = Tuple::new();
+
+                    if vars[0].kind == Kind::Tuple {
+                        let tuple = this.table.get_tuple(vars[0].pos);
+
+                        for i in 0..(tuple.len()) {
+                            if get_real_name(tuple.get_name(i)) == key {
+                                tuple_b.push(tuple.get(i), tuple.get_name(i), &tuple.table);
+                                break;
+                            }
+                        }
+                    }
+
+                    let name = names[0].trim_start_matches(get_real_name(&names[0]));
+                    Self::set_result(&mut this.table, name, tuple_b);
+                }
+                Instruction::END => {
+                    return (Self::get_returned(&names, &this.table), true);
+                }
+                Instruction::YIELD => {
+                    *j += 1;
+                    return (Self::get_returned(&names, &this.table), false);
+                }
                 Instruction::TUP => {
-                    let mut tuple = vars[0].get_tuple(&names[0], &this.table).unwrap();
-                    tuple.push(&vars[1], &names[1], &this.table);
+                    let tuple = {
+                        if names.len() > 2 {
+                            Tuple::from(&vec![names[1].as_str(), names[2].as_str()], &this.table)
+                        } else {
+                            let mut tuple = vars[0].get_tuple(&names[0], &this.table).unwrap();
+                            tuple.push(&vars[1], &names[1], &this.table);
+                            tuple
+                        }
+                    };
+
                     this.table.set_tuple(&names[0], tuple);
                 }
+                Instruction::TRY => {
+                    this.handlers.push((*j, vec_table.len(), this.tables.len()));
+                }
+                Instruction::CATCH => {
+                    this.handlers.pop();
+                    vec_table.set_null(CAUGHT);
+                }
                 Instruction::COND => {
                     let ans = vars[0].get_bool(&names[0], &this.table).unwrap();
                     if ans {
-                        j += 1;
+                        *j += 1;
                     }
                 }
                 Instruction::STOP => {
@@ -775,17 +1116,17 @@ impl Process {
                     eprintln!("\n{}\t: {}\t: {}\n", "name", "kind", "value");
                 }
                 Instruction::DROPLV => {
-                    let lvl = string_to_usize(&names[0]);
+                    let lvl = level + string_to_usize(&names[0]);
                     while vec_table.len() > lvl {
                         this.table = this.tables.pop().unwrap();
-                        vec_table.remove_level();
+                        vec_table.drop_level();
                     }
                     eprintln!("\nlevel: {}", vec_table.len() - 1);
                     eprintln!("\n{}\t: {}\t: {}\n", "name", "kind", "value");
                 }
             }
 
-            j += 1;
+            *j += 1;
         }
 
         eprintln!("\n------------------------------------------------------------\n");
@@ -795,12 +1136,78 @@ impl Process {
 
         eprintln!("\n---------------------------------------------------------------------\n");
 
-        return Tuple::new();
+        return (Tuple::new(), true);
+    }
+
+    // position of the catch of the try at that position
+    fn get_catch(instructions: &[(Instruction, Vec<String>)], position: usize) -> usize {
+        let mut depth = 0;
+
+        for i in (position + 1)..(instructions.len()) {
+            match instructions[i].0 {
+                Instruction::TRY => depth += 1,
+                Instruction::CATCH if depth == 0 => return i,
+                Instruction::CATCH => depth -= 1,
+                _ => {}
+            }
+        }
+
+        return instructions.len();
+    }
+
+    // line of the script where an entry was written
+    fn get_line(&self, name: &str) -> usize {
+        let name = name
+            .trim_start_matches(get_real_name(name))
+            .trim_matches(CHAR_SEP_NAME);
+        let parts: Vec<&str> = name.split_terminator(CHAR_SEP_NAME).collect();
+
+        if parts.len() < 2 {
+            return 0;
+        }
+
+        match self.lines.get(string_to_usize(parts[1])) {
+            Some(line) => *line,
+            None => 0,
+        }
+    }
+
+    fn get_returned(names: &Vec<String>, table: &Table) -> Tuple {
+        if names.len() > 1 && table.get(&names[0]).kind == Kind::Tuple {
+            return table.get(&names[0]).get_tuple(&names[0], table).unwrap();
+        } else {
+            return Tuple::from(&names.iter().map(|n| n.as_str()).collect(), table);
+        }
+    }
+
+    fn set_result(table: &mut Table, name: &str, tuple: Tuple) {
+        match tuple.len() {
+            0 => table.set_null(name, true),
+            1 => {
+                let var = tuple.get(0);
+
+                match var.kind {
+                    Kind::String => table.set_string(name, tuple.table.get_string(var.pos)),
+                    Kind::Number => table.set_number(name, tuple.table.get_number(var.pos)),
+                    Kind::BigInt => table.set_bigint(name, tuple.table.get_bigint(var.pos)),
+                    Kind::Bool => table.set_bool(name, tuple.table.get_bool(var.pos)),
+                    Kind::Tuple => table.set_tuple(name, tuple.table.get_tuple(var.pos)),
+                    Kind::Iterator => table.set_iterator(name, tuple.table.get_iterator(var.pos)),
+                    Kind::Range => table.set_range(name, tuple.table.get_range(var.pos)),
+                    Kind::Handle => table.set_handle(name, tuple.table.get_handle(var.pos)),
+                    Kind::Function => {}
+                    Kind::Operator => {}
+                    Kind::Null => table.set_null(name, true),
+                };
+            }
+            _ => table.set_tuple(name, tuple),
+        }
     }
 
     fn convert(
         &mut self,
         table: &mut Table,
+        name: &mut String,
         entry_list: &mut Vec<String>,
         operator_order: &mut Vec<Vec<usize>>,
         vec_table: &mut VecTable,
@@ -858,107 +1265,128 @@ impl Process {
                     match operator.get_priority() {
                         P_ASSIGNEMENT => {
                             if operator == Operator::Asign {
-                                self.instructions
-                                    .push((Instruction::ASG, vec![name_a, name_b]))
+                                self.push_instruction((Instruction::ASG, vec![name_a, name_b]))
                             } else {
                                 let name_a_buf = name_a.to_string();
 
                                 match operator {
                                     Operator::AddAsign => self
-                                        .instructions
-                                        .push((Instruction::ADD, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::ADD, vec![name_a, name_b])),
                                     Operator::SubAsign => self
-                                        .instructions
-                                        .push((Instruction::SUB, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::SUB, vec![name_a, name_b])),
                                     Operator::MulAsign => self
-                                        .instructions
-                                        .push((Instruction::MUL, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::MUL, vec![name_a, name_b])),
                                     Operator::DivAsign => self
-                                        .instructions
-                                        .push((Instruction::DIV, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::DIV, vec![name_a, name_b])),
                                     Operator::ModAsign => self
-                                        .instructions
-                                        .push((Instruction::MOD, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::MOD, vec![name_a, name_b])),
                                     Operator::PowAsign => self
-                                        .instructions
-                                        .push((Instruction::POW, vec![name_a, name_b])),
-                                    Operator::BandAsign => self
-                                        .instructions
-                                        .push((Instruction::BAND, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::POW, vec![name_a, name_b])),
+                                    Operator::BandAsign => self.push_instruction((
+                                        Instruction::BAND,
+                                        vec![name_a, name_b],
+                                    )),
                                     Operator::XorAsign => self
-                                        .instructions
-                                        .push((Instruction::XOR, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::XOR, vec![name_a, name_b])),
                                     Operator::BorAsign => self
-                                        .instructions
-                                        .push((Instruction::BOR, vec![name_a, name_b])),
+                                        .push_instruction((Instruction::BOR, vec![name_a, name_b])),
                                     _ => {}
                                 }
 
-                                self.instructions
-                                    .push((Instruction::ASG, vec![name_a_buf.clone(), name_a_buf]))
+                                self.push_instruction((
+                                    Instruction::ASG,
+                                    vec![name_a_buf.clone(), name_a_buf],
+                                ))
                             }
                         }
                         _ => match operator {
                             Operator::Not => {
-                                self.instructions.push((Instruction::NOT, vec![name_b]));
+                                self.push_instruction((Instruction::NOT, vec![name_b]));
                                 delete = (false, false);
                             }
-                            Operator::Pow => self
-                                .instructions
-                                .push((Instruction::POW, vec![name_a, name_b])),
-                            Operator::Mul => self
-                                .instructions
-                                .push((Instruction::MUL, vec![name_a, name_b])),
-                            Operator::Div => self
-                                .instructions
-                                .push((Instruction::DIV, vec![name_a, name_b])),
-                            Operator::DivInt => self
-                                .instructions
-                                .push((Instruction::IDIV, vec![name_a, name_b])),
-                            Operator::Mod => self
-                                .instructions
-                                .push((Instruction::MOD, vec![name_a, name_b])),
-                            Operator::Add => self
-                                .instructions
-                                .push((Instruction::ADD, vec![name_a, name_b])),
-                            Operator::Sub => self
-                                .instructions
-                                .push((Instruction::SUB, vec![name_a, name_b])),
-                            Operator::Band => self
-                                .instructions
-                                .push((Instruction::BAND, vec![name_a, name_b])),
-                            Operator::Xor => self
-                                .instructions
-                                .push((Instruction::XOR, vec![name_a, name_b])),
-                            Operator::Bor => self
-                                .instructions
-                                .push((Instruction::BOR, vec![name_a, name_b])),
-                            Operator::Equal => self
-                                .instructions
-                                .push((Instruction::EQU, vec![name_a, name_b])),
-                            Operator::NotEqual => self
-                                .instructions
-                                .push((Instruction::NEQU, vec![name_a, name_b])),
-                            Operator::GreaterEqual => self
-                                .instructions
-                                .push((Instruction::EGRE, vec![name_a, name_b])),
-                            Operator::LesserEqual => self
-                                .instructions
-                                .push((Instruction::ELES, vec![name_a, name_b])),
-                            Operator::Greater => self
-                                .instructions
-                                .push((Instruction::GRE, vec![name_a, name_b])),
-                            Operator::Lesser => self
-                                .instructions
-                                .push((Instruction::LES, vec![name_a, name_b])),
-                            Operator::And => self
-                                .instructions
-                                .push((Instruction::AND, vec![name_a, name_b])),
-                            Operator::Or => self
-                                .instructions
-                                .push((Instruction::OR, vec![name_a, name_b])),
+                            Operator::Pow => {
+                                self.push_instruction((Instruction::POW, vec![name_a, name_b]))
+                            }
+                            Operator::Mul => {
+                                self.push_instruction((Instruction::MUL, vec![name_a, name_b]))
+                            }
+                            Operator::Div => {
+                                self.push_instruction((Instruction::DIV, vec![name_a, name_b]))
+                            }
+                            Operator::DivInt => {
+                                self.push_instruction((Instruction::IDIV, vec![name_a, name_b]))
+                            }
+                            Operator::Mod => {
+                                self.push_instruction((Instruction::MOD, vec![name_a, name_b]))
+                            }
+                            Operator::Add => {
+                                self.push_instruction((Instruction::ADD, vec![name_a, name_b]))
+                            }
+                            Operator::Sub => {
+                                self.push_instruction((Instruction::SUB, vec![name_a, name_b]))
+                            }
+                            Operator::Band => {
+                                self.push_instruction((Instruction::BAND, vec![name_a, name_b]))
+                            }
+                            Operator::Xor => {
+                                self.push_instruction((Instruction::XOR, vec![name_a, name_b]))
+                            }
+                            Operator::Bor => {
+                                self.push_instruction((Instruction::BOR, vec![name_a, name_b]))
+                            }
+                            Operator::Equal => {
+                                self.push_instruction((Instruction::EQU, vec![name_a, name_b]))
+                            }
+                            Operator::NotEqual => {
+                                self.push_instruction((Instruction::NEQU, vec![name_a, name_b]))
+                            }
+                            Operator::GreaterEqual => {
+                                self.push_instruction((Instruction::EGRE, vec![name_a, name_b]))
+                            }
+                            Operator::LesserEqual => {
+                                self.push_instruction((Instruction::ELES, vec![name_a, name_b]))
+                            }
+                            Operator::Greater => {
+                                self.push_instruction((Instruction::GRE, vec![name_a, name_b]))
+                            }
+                            Operator::Lesser => {
+                                self.push_instruction((Instruction::LES, vec![name_a, name_b]))
+                            }
+                            Operator::In => {
+                                self.push_instruction((Instruction::IN, vec![name_a, name_b]))
+                            }
+                            Operator::Range => {
+                                self.push_instruction((Instruction::RNG, vec![name_a, name_b]))
+                            }
+                            Operator::RangeInclusive => {
+                                self.push_instruction((Instruction::IRNG, vec![name_a, name_b]))
+                            }
+                            Operator::And => {
+                                self.push_instruction((Instruction::AND, vec![name_a, name_b]))
+                            }
+                            Operator::Or => {
+                                self.push_instruction((Instruction::OR, vec![name_a, name_b]))
+                            }
+                            Operator::Coalesce => {
+                                self.push_instruction((Instruction::COAL, vec![name_a, name_b]))
+                            }
                             Operator::Return => {
-                                self.instructions.push((Instruction::END, vec![name_b]))
+                                self.push_instruction((Instruction::END, vec![name_b]))
+                            }
+                            Operator::Yield => {
+                                for (_position, level, pos, function_kind) in
+                                    self.incomplete_function.iter().rev()
+                                {
+                                    if *function_kind == FunctionKind::Function {
+                                        vec_table
+                                            .get_level(*level)
+                                            .get_mut_function(*pos)
+                                            .generator = true;
+                                        break;
+                                    }
+                                }
+
+                                self.push_instruction((Instruction::YIELD, vec![name_b]))
                             }
                             Operator::End => {
                                 let (mut position, mut level, mut pos, mut function_kind) =
@@ -969,16 +1397,16 @@ impl Process {
                                         vec_table.get_level(level).get_mut_function(pos).table =
                                             vec_table.remove_level();
 
-                                        self.instructions.insert(
+                                        self.insert_instruction(
                                             position + self.loop_counter.len(),
                                             self.goto_setup(2),
                                         );
-                                        self.instructions.push((Instruction::END, Vec::new()));
+                                        self.push_instruction((Instruction::END, Vec::new()));
                                     }
                                     FunctionKind::Conditinal => {
                                         loop {
                                             if function_kind == FunctionKind::Conditinal {
-                                                self.instructions.insert(
+                                                self.insert_instruction(
                                                     position,
                                                     self.goto_setup(
                                                         1 - self.loop_counter.len() as isize
@@ -1016,9 +1444,11 @@ impl Process {
                                         }
 
                                         vec_table.remove_level();
-                                        self.instructions.push((
+                                        self.push_instruction((
                                             Instruction::DROPLV,
-                                            vec![usize_to_string(vec_table.len())],
+                                            vec![usize_to_string(
+                                                vec_table.len() - self.get_level(),
+                                            )],
                                         ));
                                     }
                                     FunctionKind::Loop => {
@@ -1045,7 +1475,7 @@ impl Process {
 
                                                 println!("\n--------------\n{}\n{}\n", t, t);*/
 
-                                                self.instructions.insert(
+                                                self.insert_instruction(
                                                     position_loop + count_loop,
                                                     (
                                                         Instruction::GOTO,
@@ -1072,12 +1502,14 @@ impl Process {
                                         }
 
                                         vec_table.remove_level();
-                                        self.instructions.push((
+                                        self.push_instruction((
                                             Instruction::DROPLV,
-                                            vec![usize_to_string(vec_table.len())],
+                                            vec![usize_to_string(
+                                                vec_table.len() - self.get_level(),
+                                            )],
                                         ));
 
-                                        self.instructions.insert(
+                                        self.insert_instruction(
                                             self.instructions.len(),
                                             (
                                                 Instruction::GOTO,
@@ -1090,9 +1522,11 @@ impl Process {
 
                                         self.loop_counter.pop();
 
-                                        self.instructions.push((
+                                        self.push_instruction((
                                             Instruction::DROPLV,
-                                            vec![usize_to_string(vec_table.len())],
+                                            vec![usize_to_string(
+                                                vec_table.len() - self.get_level(),
+                                            )],
                                         ));
                                     }
                                 }
@@ -1100,12 +1534,37 @@ impl Process {
                                 delete = (false, false);
                             }
                             Operator::SeparatorTuple => {
-                                let mut tuple =
-                                    table.get(&name_a).get_tuple(&name_a, &table).unwrap();
-                                tuple.push(&table.get(&name_b), &name_b, &table);
-                                table.set_tuple(&name_a, tuple);
-                                //self.instructions
-                                // .push((Instruction::TUP, vec![name_a, name_b]));
+                                if table.get(&name_a).kind == Kind::Tuple {
+                                    let mut tuple =
+                                        table.get(&name_a).get_tuple(&name_a, &table).unwrap();
+                                    tuple.push(&table.get(&name_b), &name_b, &table);
+                                    table.set_tuple(&name_a, tuple);
+
+                                    self.push_instruction((Instruction::TUP, vec![name_a, name_b]));
+                                } else {
+                                    // the tuple gets its own name so the first element can still be computed before it
+                                    let name_tuple = entry_list[operator_position].to_string();
+
+                                    table.set_tuple(
+                                        &name_tuple,
+                                        Tuple::from(
+                                            &vec![name_a.as_str(), name_b.as_str()],
+                                            &table,
+                                        ),
+                                    );
+
+                                    if *name == name_a || *name == entry_list[operator_position - 1]
+                                    {
+                                        *name = name_tuple.to_string();
+                                    }
+
+                                    entry_list[operator_position - 1] = name_tuple.to_string();
+
+                                    self.push_instruction((
+                                        Instruction::TUP,
+                                        vec![name_tuple, name_a, name_b],
+                                    ));
+                                }
                             }
                             Operator::SetFunction => {
                                 let function = Function::new(
@@ -1132,17 +1591,52 @@ impl Process {
 
                                 vec_table.add_level(Table::new());
                             }
-                            Operator::UseFunction => self
-                                .instructions
-                                .push((Instruction::GOTOFN, vec![name_a, name_b])),
+                            Operator::UseFunction => {
+                                self.push_instruction((Instruction::GOTOFN, vec![name_a, name_b]))
+                            }
+                            Operator::Access | Operator::OptionalAccess => {
+                                // the result can't go in the receiver's variable or it would be fetched again
+                                entry_list[operator_position - 1] = name_a
+                                    .trim_start_matches(get_real_name(&name_a))
+                                    .to_string();
+
+                                let name_fn = entry_list[operator_position + 1].to_string();
+
+                                if get_real_name(&name_fn).contains("(") {
+                                    let mut name_args =
+                                        entry_list[operator_position + 2].to_string();
+                                    let real_name = get_real_name(&name_args);
+
+                                    if real_name.contains("(") {
+                                        name_args =
+                                            name_args.trim_start_matches(real_name).to_string();
+                                    }
+
+                                    self.push_instruction((
+                                        {
+                                            if operator == Operator::OptionalAccess {
+                                                Instruction::OGOTOMT
+                                            } else {
+                                                Instruction::GOTOMT
+                                            }
+                                        },
+                                        vec![name_a, name_fn, name_args],
+                                    ));
+
+                                    entry_list.remove(operator_position + 2);
+                                } else {
+                                    // getting an element of something that isn't a tuple already gives null
+                                    self.push_instruction((Instruction::GET, vec![name_a, name_b]));
+                                }
+                            }
                             Operator::If => {
                                 //delete = (false, false);
-                                self.instructions.insert(
+                                self.insert_instruction(
                                     self.instructions.len() - operation_count,
                                     (Instruction::UPLV, Vec::new()),
                                 );
                                 vec_table.add_level(Table::new());
-                                self.instructions.push((Instruction::COND, vec![name_b]));
+                                self.push_instruction((Instruction::COND, vec![name_b]));
 
                                 self.incomplete_function.push((
                                     self.instructions.len(),
@@ -1159,7 +1653,7 @@ impl Process {
                                 let (position, _level, pos, _function_kind) =
                                     self.incomplete_function.pop().unwrap();
 
-                                self.instructions.insert(
+                                self.insert_instruction(
                                     position,
                                     self.goto_setup(
                                         2 - self.loop_counter.len() as isize
@@ -1180,7 +1674,7 @@ impl Process {
                                 let (position, _level, pos, _function_kind) =
                                     self.incomplete_function.pop().unwrap();
 
-                                self.instructions.insert(
+                                self.insert_instruction(
                                     position,
                                     self.goto_setup(
                                         2 - operation_count as isize
@@ -1196,7 +1690,7 @@ impl Process {
                                     FunctionKind::Conditinal,
                                 ));
 
-                                self.instructions.push((Instruction::COND, vec![name_b]));
+                                self.push_instruction((Instruction::COND, vec![name_b]));
 
                                 self.incomplete_function.push((
                                     self.instructions.len(),
@@ -1212,7 +1706,7 @@ impl Process {
                                 delete = (false, false);
                             }
                             Operator::Loop => {
-                                self.instructions.push((Instruction::UPLV, Vec::new()));
+                                self.push_instruction((Instruction::UPLV, Vec::new()));
                                 vec_table.add_level(Table::new());
 
                                 self.incomplete_function.push((
@@ -1240,7 +1734,7 @@ impl Process {
                             }
                             Operator::Continue => {}
                             Operator::Stop => {
-                                self.instructions.push((Instruction::STOP, Vec::new()));
+                                self.push_instruction((Instruction::STOP, Vec::new()));
                                 delete = (false, false);
                             }
                             _ => break,
@@ -1276,6 +1770,26 @@ impl Process {
         //return instructions;
     }
 
+    // the instructions are only written while the script is read, before any run shares them
+    fn push_instruction(&mut self, instruction: (Instruction, Vec<String>)) {
+        Rc::make_mut(&mut self.instructions).push(instruction);
+    }
+
+    fn insert_instruction(&mut self, position: usize, instruction: (Instruction, Vec<String>)) {
+        Rc::make_mut(&mut self.instructions).insert(position, instruction);
+    }
+
+    // a function can be called from any level so its levels are counted from where its own starts
+    fn get_level(&self) -> usize {
+        for (_position, level, _pos, function_kind) in self.incomplete_function.iter().rev() {
+            if *function_kind == FunctionKind::Function {
+                return level + 2;
+            }
+        }
+
+        return self.level;
+    }
+
     fn goto_setup(&self, skip: isize) -> (Instruction, Vec<String>) {
         /*let t = format!(
             "wtf: {}, {}, {}, {}, {}",
@@ -1307,6 +1821,13 @@ impl Clone for Process {
             incomplete_loop: self.incomplete_loop.clone(),
             tables: self.tables.clone(),
             loop_counter: self.loop_counter.clone(),
+            level: self.level,
+            lines: self.lines.clone(),
+            tests: self.tests.clone(),
+            handlers: self.handlers.clone(),
+            tries: self.tries.clone(),
+            single_arguments: self.single_arguments.clone(),
+            for_loops: self.for_loops.clone(),
         }
     }
 }
@@ -1317,3 +1838,153 @@ pub fn get_real_name(name: &str) -> &str {
         None => name,
     }
 }
+
+// value[index] and value?[index] are calls of at() with the value as the receiver
+fn replace_index(line: &str) -> String {
+    let mut string = String::with_capacity(line.len());
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for ch in line.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == q {
+                    quote = None;
+                }
+
+                string.push(ch);
+            }
+            None => match ch {
+                '\"' | '\'' => {
+                    quote = Some(ch);
+                    string.push(ch);
+                }
+                '[' => string.push_str(".at("),
+                ']' => string.push(')'),
+                _ => string.push(ch),
+            },
+        }
+    }
+
+    return string;
+}
+
+// a comma outside of any parenthesis and string, so what is around it are different values
+fn has_comma(text: &str) -> bool {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut depth = 0;
+
+    for ch in text.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == q {
+                    quote = None;
+                }
+            }
+            None => match ch {
+                '"' | '\'' => quote = Some(ch),
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => return true,
+                _ => {}
+            },
+        }
+    }
+
+    return false;
+}
+
+// name = value at the start of an argument of a call becomes a value named with KEYWORD_PREFIX
+fn replace_keywords(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut string = String::with_capacity(line.len());
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut calls: Vec<(bool, bool)> = Vec::new(); // (the parenthesis is a call, a keyword is still open)
+    let mut start = false; // at the start of an argument of a call
+
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == q {
+                quote = None;
+            }
+
+            string.push(ch);
+            i += 1;
+            continue;
+        }
+
+        if start && (ch.is_alphabetic() || ch == '_') {
+            let mut j = i;
+            while j < chars.len() && (chars[j].is_alphanumeric() || chars[j] == '_') {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < chars.len() && chars[k].is_whitespace() {
+                k += 1;
+            }
+
+            if k < chars.len() && chars[k] == '=' && (k + 1 >= chars.len() || chars[k + 1] != '=') {
+                string.push('(');
+                string.push_str(KEYWORD_PREFIX);
+                string.extend(&chars[i..j]);
+                string.push_str(" =");
+
+                calls.last_mut().unwrap().1 = true;
+                start = false;
+                i = k + 1;
+                continue;
+            }
+        }
+
+        if !ch.is_whitespace() {
+            start = false;
+        }
+
+        match ch {
+            '\"' | '\'' => quote = Some(ch),
+            '(' => {
+                let call = i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_');
+                calls.push((call, false));
+                start = call;
+            }
+            ',' | ')' => {
+                if let Some(last) = calls.last_mut() {
+                    if last.1 {
+                        string.push(')');
+                        last.1 = false;
+                    }
+                }
+
+                if ch == ')' {
+                    calls.pop();
+                } else {
+                    start = calls.last().map(|last| last.0) == Some(true);
+                }
+            }
+            _ => {}
+        }
+
+        string.push(ch);
+        i += 1;
+    }
+
+    return string;
+}
@@ -0,0 +1,107 @@
+use num::{BigInt, Integer, One, Signed, Zero};
+
+#[allow(unused_imports)]
+use crate::{eprint, eprintln};
+
+pub struct Range {
+    pub start: BigInt,
+    pub end: BigInt,
+    pub step: BigInt,
+    pub inclusive: bool, // ..= also gives the end if the step lands on it
+}
+
+impl Range {
+    pub fn new(start: BigInt, end: BigInt, inclusive: bool) -> Self {
+        Self {
+            start: start,
+            end: end,
+            step: BigInt::one(),
+            inclusive: inclusive,
+        }
+    }
+
+    // first value that isn't in the range going in the direction of the step
+    fn get_limit(&self) -> BigInt {
+        if !self.inclusive {
+            self.end.clone()
+        } else if self.step.is_negative() {
+            &self.end - BigInt::one()
+        } else {
+            &self.end + BigInt::one()
+        }
+    }
+
+    pub fn len(&self) -> BigInt {
+        let distance = {
+            if self.step.is_negative() {
+                &self.start - self.get_limit()
+            } else {
+                self.get_limit() - &self.start
+            }
+        };
+
+        if self.step.is_zero() || !distance.is_positive() {
+            return BigInt::zero();
+        }
+
+        return distance.div_ceil(&self.step.abs());
+    }
+
+    pub fn get(&self, index: &BigInt) -> BigInt {
+        &self.start + index * &self.step
+    }
+
+    pub fn contains(&self, value: &BigInt) -> bool {
+        if self.step.is_zero() {
+            return false;
+        }
+
+        let in_bounds = {
+            if self.step.is_negative() {
+                *value <= self.start && *value > self.get_limit()
+            } else {
+                *value >= self.start && *value < self.get_limit()
+            }
+        };
+
+        return in_bounds && (value - &self.start).is_multiple_of(&self.step);
+    }
+}
+
+impl std::fmt::Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.start,
+            if self.inclusive { "..=" } else { ".." },
+            self.end
+        )?;
+
+        if !self.step.is_one() {
+            write!(f, " step {}", self.step)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::cmp::PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.end == other.end
+            && self.step == other.step
+            && self.inclusive == other.inclusive
+    }
+}
+
+impl Clone for Range {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            step: self.step.clone(),
+            inclusive: self.inclusive,
+        }
+    }
+}
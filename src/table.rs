@@ -1,6 +1,7 @@
 use crate::function::*;
 use crate::kind::*;
 use crate::operation::*;
+use crate::range::*;
 use crate::tuple::*;
 use crate::variable::*;
 use crate::vec_free::*;
@@ -20,6 +21,9 @@ pub struct Table {
     pub vec_bool: VecFree<bool>,
     pub vec_function: VecFree<Function>,
     pub vec_tuple: VecFree<Tuple>,
+    pub vec_iterator: VecFree<usize>,
+    pub vec_range: VecFree<Range>,
+    pub vec_handle: VecFree<usize>,
     //
     pub null: Variable,
 }
@@ -35,6 +39,9 @@ impl Table {
             vec_bool: VecFree::new(),
             vec_function: VecFree::new(),
             vec_tuple: VecFree::new(),
+            vec_iterator: VecFree::new(),
+            vec_range: VecFree::new(),
+            vec_handle: VecFree::new(),
             //
             null: Variable::new_null(0),
         }
@@ -89,6 +96,15 @@ impl Table {
                 Kind::Tuple => {
                     var.pos = self.vec_tuple.add(other.vec_tuple[var.pos].clone());
                 }
+                Kind::Iterator => {
+                    var.pos = self.vec_iterator.add(other.vec_iterator[var.pos]);
+                }
+                Kind::Range => {
+                    var.pos = self.vec_range.add(other.vec_range[var.pos].clone());
+                }
+                Kind::Handle => {
+                    var.pos = self.vec_handle.add(other.vec_handle[var.pos]);
+                }
             }
 
             self.variables.insert(entry, var);
@@ -120,6 +136,9 @@ impl Table {
                     self,
                 ),
             ),
+            Kind::Iterator => {}
+            Kind::Range => {}
+            Kind::Handle => {}
         }
     }
 
@@ -143,6 +162,9 @@ impl Table {
                     Kind::Bool => Variable::new_bool(pos),
                     Kind::Function => Variable::new_function(pos),
                     Kind::Tuple => Variable::new_tuple(pos),
+                    Kind::Iterator => Variable::new_iterator(pos),
+                    Kind::Range => Variable::new_range(pos),
+                    Kind::Handle => Variable::new_handle(pos),
                     Kind::Null | Kind::Operator => Variable::new_null(pos),
                 };
 
@@ -235,6 +257,36 @@ impl Table {
         }
     }
 
+    pub fn set_iterator(&mut self, entry: &str, value: usize) {
+        let pos_a = self.vec_iterator.add(value);
+        let pos_b = self.set(entry, pos_a, Kind::Iterator);
+
+        if pos_a != pos_b {
+            self.vec_iterator.remove(pos_a);
+            self.vec_iterator[pos_b] = value;
+        }
+    }
+
+    pub fn set_handle(&mut self, entry: &str, value: usize) {
+        let pos_a = self.vec_handle.add(value);
+        let pos_b = self.set(entry, pos_a, Kind::Handle);
+
+        if pos_a != pos_b {
+            self.vec_handle.remove(pos_a);
+            self.vec_handle[pos_b] = value;
+        }
+    }
+
+    pub fn set_range(&mut self, entry: &str, value: Range) {
+        let pos_a = self.vec_range.add(value.clone());
+        let pos_b = self.set(entry, pos_a, Kind::Range);
+
+        if pos_a != pos_b {
+            self.vec_range.remove(pos_a);
+            self.vec_range[pos_b] = value;
+        }
+    }
+
     pub fn clear_kind(&mut self, kind: Kind) {
         let v = self.variables.clone();
 
@@ -297,6 +349,18 @@ impl Table {
         self.vec_tuple[pos].clone()
     }
 
+    pub fn get_iterator(&self, pos: usize) -> usize {
+        self.vec_iterator[pos]
+    }
+
+    pub fn get_handle(&self, pos: usize) -> usize {
+        self.vec_handle[pos]
+    }
+
+    pub fn get_range(&self, pos: usize) -> Range {
+        self.vec_range[pos].clone()
+    }
+
     pub fn get_mut_string(&mut self, pos: usize) -> &mut String {
         &mut self.vec_string[pos]
     }
@@ -349,6 +413,15 @@ impl Table {
             Kind::Tuple => {
                 self.vec_tuple.remove(pos);
             }
+            Kind::Iterator => {
+                self.vec_iterator.remove(pos);
+            }
+            Kind::Range => {
+                self.vec_range.remove(pos);
+            }
+            Kind::Handle => {
+                self.vec_handle.remove(pos);
+            }
             _ => {}
         }
     }
@@ -371,6 +444,9 @@ impl Clone for Table {
             vec_bool: self.vec_bool.clone(),
             vec_function: self.vec_function.clone(),
             vec_tuple: self.vec_tuple.clone(),
+            vec_iterator: self.vec_iterator.clone(),
+            vec_range: self.vec_range.clone(),
+            vec_handle: self.vec_handle.clone(),
             //
             null: Variable::new_null(0),
         }
@@ -1,10 +1,11 @@
 use crate::get_real_name;
 use crate::kind::*;
+use crate::range::*;
 use crate::table::*;
 use crate::usize_to_string;
 use crate::variable::*;
 use crate::CHAR_SEP_NAME;
-use num::BigInt;
+use num::{BigInt, FromPrimitive};
 
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
@@ -81,11 +82,24 @@ impl Tuple {
                     self.table
                         .set_tuple(name_b, table.vec_tuple[var.pos].clone());
                 }
+                Kind::Iterator => {
+                    self.table.set_iterator(name_b, table.vec_iterator[var.pos]);
+                }
+                Kind::Range => {
+                    self.table
+                        .set_range(name_b, table.vec_range[var.pos].clone());
+                }
+                Kind::Handle => {
+                    self.table.set_handle(name_b, table.vec_handle[var.pos]);
+                }
                 Kind::Operator => {}
                 Kind::Null => {
                     self.table.set_null(name_b, true);
                 }
-                Kind::Function => {}
+                Kind::Function => {
+                    self.table
+                        .set_function(name_b, table.vec_function[var.pos].clone());
+                }
             }
         }
     }
@@ -119,11 +133,24 @@ impl Tuple {
                 self.table
                     .set_tuple(&name, table.vec_tuple[var.pos].clone());
             }
+            Kind::Iterator => {
+                self.table.set_iterator(&name, table.vec_iterator[var.pos]);
+            }
+            Kind::Range => {
+                self.table
+                    .set_range(&name, table.vec_range[var.pos].clone());
+            }
+            Kind::Handle => {
+                self.table.set_handle(&name, table.vec_handle[var.pos]);
+            }
             Kind::Operator => {}
             Kind::Null => {
                 self.table.set_null(&name, true);
             }
-            Kind::Function => {}
+            Kind::Function => {
+                self.table
+                    .set_function(&name, table.vec_function[var.pos].clone());
+            }
         }
 
         self.order.push(name);
@@ -159,6 +186,24 @@ impl Tuple {
         self.order.push(name);
     }
 
+    pub fn set_iterator(&mut self, entry: &str, value: usize) {
+        let name = self.get_new_name(entry);
+        self.table.set_iterator(&name, value);
+        self.order.push(name);
+    }
+
+    pub fn set_range(&mut self, entry: &str, value: Range) {
+        let name = self.get_new_name(entry);
+        self.table.set_range(&name, value);
+        self.order.push(name);
+    }
+
+    pub fn set_handle(&mut self, entry: &str, value: usize) {
+        let name = self.get_new_name(entry);
+        self.table.set_handle(&name, value);
+        self.order.push(name);
+    }
+
     pub fn set_null(&mut self, entry: &str) {
         let name = self.get_new_name(entry);
         self.table.set_null(&name, true);
@@ -219,28 +264,28 @@ impl std::cmp::PartialEq for Tuple {
                     match var_self.kind {
                         Kind::String => {
                             if var_self.get_string(name_self, &self.table).unwrap()
-                                != var_other.get_string(name_other, &self.table).unwrap()
+                                != var_other.get_string(name_other, &other.table).unwrap()
                             {
                                 return false;
                             }
                         }
                         Kind::Number => {
                             if var_self.get_number(name_self, &self.table).unwrap()
-                                != var_other.get_number(name_other, &self.table).unwrap()
+                                != var_other.get_number(name_other, &other.table).unwrap()
                             {
                                 return false;
                             }
                         }
                         Kind::BigInt => {
                             if var_self.get_bigint(name_self, &self.table).unwrap()
-                                != var_other.get_bigint(name_other, &self.table).unwrap()
+                                != var_other.get_bigint(name_other, &other.table).unwrap()
                             {
                                 return false;
                             }
                         }
                         Kind::Bool => {
                             if var_self.get_bool(name_self, &self.table).unwrap()
-                                != var_other.get_bool(name_other, &self.table).unwrap()
+                                != var_other.get_bool(name_other, &other.table).unwrap()
                             {
                                 return false;
                             }
@@ -254,7 +299,28 @@ impl std::cmp::PartialEq for Tuple {
                         Kind::Null => {}
                         Kind::Tuple => {
                             if var_self.get_tuple(name_self, &self.table).unwrap()
-                                != var_other.get_tuple(name_other, &self.table).unwrap()
+                                != var_other.get_tuple(name_other, &other.table).unwrap()
+                            {
+                                return false;
+                            }
+                        }
+                        Kind::Iterator => {
+                            if var_self.get_iterator(name_self, &self.table).unwrap()
+                                != var_other.get_iterator(name_other, &other.table).unwrap()
+                            {
+                                return false;
+                            }
+                        }
+                        Kind::Range => {
+                            if var_self.get_range(name_self, &self.table).unwrap()
+                                != var_other.get_range(name_other, &other.table).unwrap()
+                            {
+                                return false;
+                            }
+                        }
+                        Kind::Handle => {
+                            if var_self.get_handle(name_self, &self.table).unwrap()
+                                != var_other.get_handle(name_other, &other.table).unwrap()
                             {
                                 return false;
                             }
@@ -280,3 +346,50 @@ impl Clone for Tuple {
         }
     }
 }
+
+// the same elements give the same hash whatever their names are, like ==
+impl std::hash::Hash for Tuple {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for i in 0..(self.len()) {
+            let var = self.table.get(&self.order[i]);
+
+            // 1.0 == 1 so a float without a fraction hashes like the integer
+            let int = match var.kind {
+                Kind::Number => {
+                    let num = self.table.get_number(var.pos);
+                    BigInt::from_f64(num).filter(|_| num.fract() == 0.0)
+                }
+                _ => None,
+            };
+
+            if let Some(int) = int {
+                Kind::BigInt.get_str().hash(state);
+                int.hash(state);
+                continue;
+            }
+
+            var.kind.get_str().hash(state);
+
+            match var.kind {
+                Kind::String => self.table.get_string(var.pos).hash(state),
+                Kind::Number => self.table.get_number(var.pos).to_bits().hash(state),
+                Kind::BigInt => self.table.get_bigint(var.pos).hash(state),
+                Kind::Bool => self.table.get_bool(var.pos).hash(state),
+                Kind::Tuple => self.table.get_tuple(var.pos).hash(state),
+                Kind::Iterator => self.table.get_iterator(var.pos).hash(state),
+                Kind::Range => {
+                    let range = self.table.get_range(var.pos);
+
+                    range.start.hash(state);
+                    range.end.hash(state);
+                    range.step.hash(state);
+                    range.inclusive.hash(state);
+                }
+                Kind::Handle => self.table.get_handle(var.pos).hash(state),
+                Kind::Operator | Kind::Null | Kind::Function => {}
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use crate::range::*;
 use crate::tuple::*;
-use crate::{function::*, kind::*, table::*, Operator, OPERATORS};
+use crate::{function::*, get_real_name, kind::*, table::*, Operator, OPERATORS};
 use num::{BigInt, FromPrimitive, One, ToPrimitive, Zero};
 
 #[allow(unused_imports)]
@@ -50,6 +51,18 @@ impl Variable {
         Variable::new(Kind::Tuple, pos)
     }
 
+    pub fn new_iterator(pos: usize) -> Self {
+        Variable::new(Kind::Iterator, pos)
+    }
+
+    pub fn new_range(pos: usize) -> Self {
+        Variable::new(Kind::Range, pos)
+    }
+
+    pub fn new_handle(pos: usize) -> Self {
+        Variable::new(Kind::Handle, pos)
+    }
+
     pub fn set(&mut self, kind: Kind, pos: usize) {
         self.kind = kind;
         self.pos = pos;
@@ -72,7 +85,7 @@ impl Variable {
             Kind::Null => Ok("".to_string()),
             Kind::Function => Ok(format!(
                 "{}{}",
-                entry.get(..(entry.len() - 2)).unwrap(),
+                get_real_name(entry).trim_end_matches("()"),
                 table.get_function(self.pos).arguments
             )),
             Kind::Tuple => {
@@ -83,7 +96,11 @@ impl Variable {
                 } else {
                     Ok(format!("{}", table.get_tuple(self.pos)))
                 }
-            } //_ => Err(self.get_err(entry, Kind::String)), // here in case I need it later and for consistency
+            }
+            Kind::Iterator => Ok(format!("iterator({})", table.get_iterator(self.pos))),
+            Kind::Range => Ok(table.get_range(self.pos).to_string()),
+            Kind::Handle => Ok(format!("handle({})", table.get_handle(self.pos))),
+            //_ => Err(self.get_err(entry, Kind::String)), // here in case I need it later and for consistency
         }
     }
 
@@ -170,6 +187,27 @@ impl Variable {
         }
     }
 
+    pub fn get_iterator(&self, entry: &str, table: &Table) -> Result<usize, String> {
+        match self.kind {
+            Kind::Iterator => Ok(table.get_iterator(self.pos)),
+            _ => Err(self.get_err(entry, Kind::Iterator)),
+        }
+    }
+
+    pub fn get_handle(&self, entry: &str, table: &Table) -> Result<usize, String> {
+        match self.kind {
+            Kind::Handle => Ok(table.get_handle(self.pos)),
+            _ => Err(self.get_err(entry, Kind::Handle)),
+        }
+    }
+
+    pub fn get_range(&self, entry: &str, table: &Table) -> Result<Range, String> {
+        match self.kind {
+            Kind::Range => Ok(table.get_range(self.pos)),
+            _ => Err(self.get_err(entry, Kind::Range)),
+        }
+    }
+
     pub fn get_tuple(&self, entry: &str, table: &Table) -> Result<Tuple, String> {
         match self.kind {
             Kind::Tuple => Ok(table.get_tuple(self.pos)),
@@ -44,6 +44,37 @@ impl<T: Clone> VecFree<T> {
         }
     }
 
+    // number of slots, the free ones included
+    pub fn len(&self) -> usize {
+        return self.vec_val.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.vec_val.is_empty();
+    }
+
+    // the slot holds a value that wasn't removed
+    pub fn contains(&self, pos: usize) -> bool {
+        return pos < self.vec_val.len() && !self.free_val.contains(&pos);
+    }
+
+    // None for a slot that was removed
+    pub fn get(&self, pos: usize) -> Option<&T> {
+        if self.contains(pos) {
+            return Some(&self.vec_val[pos]);
+        }
+
+        return None;
+    }
+
+    pub fn get_mut(&mut self, pos: usize) -> Option<&mut T> {
+        if self.contains(pos) {
+            return Some(&mut self.vec_val[pos]);
+        }
+
+        return None;
+    }
+
     pub fn retrieve_all(&self) -> Vec<T> {
         let mut v = self.vec_val.clone();
         let mut count = 0;
@@ -1,20 +1,52 @@
 use crate::function::*;
+use crate::handle::*;
+use crate::iterator::*;
+use crate::kind::*;
+use crate::range::*;
 use crate::table::*;
 use crate::tuple::*;
 use crate::variable::*;
+use crate::vec_free::*;
 use num::BigInt;
+use rand::prelude::*;
+use regex::Regex;
+use std::time::Instant;
 
 #[allow(unused_imports)]
 use crate::{eprint, eprintln};
 
 pub struct VecTable {
     tables: Vec<Table>,
+    pub iterators: VecFree<Iter>,
+    pub handles: VecFree<Handle>,
+    pub error: Option<String>, // set when something failed, every process stops until it is taken
+    pub exit: Option<i32>,     // set by exit(), every process stops and main exits with it
+    pub line: usize,           // line of the script of the last function called
+    pub rng: StdRng, // every random value comes from it so seeding it makes a run reproducible
+    pub start: Instant, // monotonic() counts from it
+    pub regexes: Vec<(String, Regex)>, // the last patterns compiled so a loop doesn't compile them again, the most recent at the end
+    #[cfg(target_family = "unix")]
+    pub keys: Option<termion::AsyncReader>, // what getkey() reads, made the first time it is used
+    #[cfg(target_family = "unix")]
+    pub pending_keys: Vec<u8>, // read but not yet turned into a key
 }
 
 impl VecTable {
     pub fn new() -> Self {
         Self {
             tables: Vec::from([Table::new()]),
+            iterators: VecFree::new(),
+            handles: VecFree::new(),
+            error: None,
+            exit: None,
+            line: 0,
+            rng: StdRng::from_entropy(),
+            start: Instant::now(),
+            regexes: Vec::new(),
+            #[cfg(target_family = "unix")]
+            keys: None,
+            #[cfg(target_family = "unix")]
+            pending_keys: Vec::new(),
         }
     }
 
@@ -82,6 +114,18 @@ impl VecTable {
         self.tables[level].set_tuple(entry, value);
     }
 
+    pub fn set_iterator_specified(&mut self, level: usize, entry: &str, value: usize) {
+        self.tables[level].set_iterator(entry, value);
+    }
+
+    pub fn set_handle_specified(&mut self, level: usize, entry: &str, value: usize) {
+        self.tables[level].set_handle(entry, value);
+    }
+
+    pub fn set_range_specified(&mut self, level: usize, entry: &str, value: Range) {
+        self.tables[level].set_range(entry, value);
+    }
+
     pub fn set_null_specified(&mut self, level: usize, entry: &str) {
         self.tables[level].set_null(entry, true);
     }
@@ -145,6 +189,39 @@ impl VecTable {
         self.set_tuple_specified(self.tables.len() - 1, entry, value);
     }
 
+    pub fn set_iterator(&mut self, entry: &str, value: usize) {
+        for i in (0..(self.tables.len())).rev() {
+            if self.tables[i].contains(entry) {
+                self.set_iterator_specified(i, entry, value);
+                return;
+            }
+        }
+
+        self.set_iterator_specified(self.tables.len() - 1, entry, value);
+    }
+
+    pub fn set_handle(&mut self, entry: &str, value: usize) {
+        for i in (0..(self.tables.len())).rev() {
+            if self.tables[i].contains(entry) {
+                self.set_handle_specified(i, entry, value);
+                return;
+            }
+        }
+
+        self.set_handle_specified(self.tables.len() - 1, entry, value);
+    }
+
+    pub fn set_range(&mut self, entry: &str, value: Range) {
+        for i in (0..(self.tables.len())).rev() {
+            if self.tables[i].contains(entry) {
+                self.set_range_specified(i, entry, value);
+                return;
+            }
+        }
+
+        self.set_range_specified(self.tables.len() - 1, entry, value);
+    }
+
     pub fn set_null(&mut self, entry: &str) {
         for i in (0..(self.tables.len())).rev() {
             if self.tables[i].contains(entry) {
@@ -184,12 +261,129 @@ impl VecTable {
     pub fn get_level(&mut self, level: usize) -> &mut Table {
         &mut self.tables[level]
     }
+
+    // the error starts with where in the script it happened
+    pub fn set_error(&mut self, message: &str) {
+        let path = {
+            let table = &self.tables[0];
+            table.get("path").get_string("path", table).unwrap()
+        };
+
+        self.error = Some(format!("{}:{}: {}", path, self.line, message));
+    }
+
+    // the iterator is taken out while it runs since a generator needs the levels to resume
+    pub fn next_iterator(&mut self, pos: usize) -> Option<Tuple> {
+        if !self.iterators.contains(pos) {
+            return None;
+        }
+
+        let mut iterator = std::mem::replace(&mut self.iterators[pos], Iter::Done);
+        let value = iterator.next(self);
+        self.iterators[pos] = iterator;
+
+        return value;
+    }
+
+    pub fn has_next_iterator(&mut self, pos: usize) -> bool {
+        if !self.iterators.contains(pos) {
+            return false;
+        }
+
+        let mut iterator = std::mem::replace(&mut self.iterators[pos], Iter::Done);
+        let more = iterator.has_next(self);
+        self.iterators[pos] = iterator;
+
+        return more;
+    }
+
+    // iterators held by a variable, before it is given another value
+    pub fn get_held_iterators(&mut self, entry: &str) -> Vec<usize> {
+        let mut held = Vec::new();
+
+        if let Some((level, var)) = self.get(entry) {
+            match var.kind {
+                Kind::Iterator => held.push(level.get_iterator(var.pos)),
+                Kind::Tuple => get_held_iterators(&level.vec_tuple[var.pos].table, &mut held),
+                _ => {}
+            }
+        }
+
+        return held;
+    }
+
+    // a block or a function ended, the iterators only its variables held go with it
+    pub fn drop_level(&mut self) {
+        let table = self.remove_level();
+
+        let mut dropped = Vec::new();
+        get_held_iterators(&table, &mut dropped);
+
+        self.drop_iterators(dropped);
+    }
+
+    // frees the slots of iterators a variable stopped holding, unless another one still holds them
+    pub fn drop_iterators(&mut self, mut dropped: Vec<usize>) {
+        while let Some(pos) = dropped.pop() {
+            if !self.iterators.contains(pos) || self.holds_iterator(pos) {
+                continue;
+            }
+
+            let iterator = std::mem::replace(&mut self.iterators[pos], Iter::Done);
+            self.iterators.remove(pos);
+
+            // a generator stopped before its end lets go of what its own variables held
+            if let Iter::Generator(generator) = iterator {
+                for table in generator.tables.iter() {
+                    get_held_iterators(table, &mut dropped);
+                }
+            }
+        }
+    }
+
+    fn holds_iterator(&self, pos: usize) -> bool {
+        let mut held = Vec::new();
+
+        for table in self.tables.iter() {
+            get_held_iterators(table, &mut held);
+        }
+
+        for i in 0..(self.iterators.len()) {
+            if !self.iterators.contains(i) {
+                continue;
+            }
+
+            if let Iter::Generator(generator) = &self.iterators[i] {
+                for table in generator.tables.iter() {
+                    get_held_iterators(table, &mut held);
+                }
+
+                if let Some(value) = &generator.peeked {
+                    get_held_iterators(&value.table, &mut held);
+                }
+            }
+        }
+
+        return held.contains(&pos);
+    }
 }
 
 impl Clone for VecTable {
     fn clone(&self) -> Self {
         Self {
             tables: self.tables.clone(),
+            iterators: self.iterators.clone(),
+            handles: self.handles.clone(),
+            error: self.error.clone(),
+            exit: self.exit,
+            line: self.line,
+            rng: self.rng.clone(),
+            start: self.start,
+            regexes: self.regexes.clone(),
+            #[cfg(target_family = "unix")]
+            keys: None,
+            #[cfg(target_family = "unix")]
+            pending_keys: self.pending_keys.clone(),
         }
     }
 }
@@ -0,0 +1,133 @@
+use std::net::TcpListener;
+use std::path::Path;
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+
+// a script of tests/servers running in the background, it is stopped even when a test fails
+struct Server(Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+// the port is free once the listener is dropped, the server takes it right after
+fn free_port() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    return listener.local_addr().unwrap().port();
+}
+
+fn start_server(name: &str, port: u16) -> Server {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("servers")
+        .join(name);
+
+    let child = Command::new(env!("CARGO_BIN_EXE_script"))
+        .env("PORT", port.to_string())
+        .current_dir(dir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    return Server(child);
+}
+
+// runs every test block of the .te files in tests/ with `script test`
+#[test]
+fn script_tests() {
+    let http_port = free_port();
+    let ws_port = free_port();
+    let _http_server = start_server("http", http_port);
+    let _ws_server = start_server("ws", ws_port);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_script"))
+        .args(["test", "tests"])
+        .env("HTTP_PORT", http_port.to_string())
+        .env("WS_PORT", ws_port.to_string())
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "{}{}", stdout, stderr);
+}
+
+#[test]
+fn failed_test_exits_with_an_error() {
+    let dir = std::env::temp_dir().join(format!("script-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("fail.te"),
+        "test \"passes\"\n    assert(true)\nend\n\ntest \"fails\"\n    assert(1 == 2, \"wrong\")\nend\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_script"))
+        .arg("test")
+        .arg(&dir)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(!output.status.success());
+    assert!(stdout.contains("ok   "), "{}", stdout);
+    assert!(stdout.contains("fail.te:6: assertion failed: wrong"), "{}", stdout);
+    assert!(stdout.contains("1 passed, 1 failed"), "{}", stdout);
+}
+
+// runs the text as the test.te of a directory of its own, gives back whether it succeeded and its stdout
+fn run_script(name: &str, text: &str, stdin: &[u8]) -> (bool, String) {
+    let dir = std::env::temp_dir().join(format!("script-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("test.te"), text).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_script"))
+        .current_dir(&dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    return (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    );
+}
+
+// read_int() and read_float() take the end of their line with them, stdin_lines() goes on from there
+#[test]
+fn stdin_is_read_line_after_line() {
+    let (success, stdout) = run_script(
+        "stdin",
+        "a = read_int()\nb = read_float()\nname = input(\"name? \")\nprint(a, \" \", b, \" \", name)\nfor line in stdin_lines()\n    print(\"> \", line)\nend\nprint(read() ?? \"eof\")\n",
+        b"42\n2.5\nbob\n1 2\nlast\n",
+    );
+
+    assert!(success, "{}", stdout);
+    assert_eq!(stdout, "name? 42 2.5 bob\n> 1 2\n> last\neof\n");
+}
+
+#[test]
+fn print_takes_sep_and_end() {
+    let (success, stdout) = run_script(
+        "print",
+        "print(\"a\", \"b\", \"c\", sep = \", \")\nprint(\"no newline\", end = \"\")\nprint(\" | same line\")\nprint(1, 2, 3, sep = \"-\", end = \"!\\n\")\n",
+        b"",
+    );
+
+    assert!(success, "{}", stdout);
+    assert_eq!(stdout, "a, b, c\nno newline | same line\n1-2-3!\n");
+}